@@ -129,3 +129,304 @@ pub fn what(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     TokenStream::new()
 }
 
+
+#[proc_macro_derive(GuiEnum, attributes(gui))]
+///Implements the trait GuiEnum of rust-gui for an enum without fields so it can be used with a typed Combo or RadioGroup.
+/// 
+/// The label of a variant is its name unless it is overridden with `#[gui(label = "...")]`.
+/// 
+/// Example:
+/// #[derive(Clone, Copy, PartialEq, GuiEnum)]
+/// enum Mode { Fast, #[gui(label = "high quality")] Quality }
+pub fn derive_gui_enum(input: TokenStream) -> TokenStream {
+    let mut tokens = proc_macro2::TokenStream::from(input).into_iter().peekable();
+    let mut name = None;
+    let mut body = None;
+
+    while let Some(tt) = tokens.next() {
+        match tt {
+            proc_macro2::TokenTree::Ident(i) if i == "enum" => {
+                match tokens.next() {
+                    Some(proc_macro2::TokenTree::Ident(i)) => name = Some(i),
+                    _ => panic!("expected the name of the enum"),
+                }
+            },
+            proc_macro2::TokenTree::Ident(i) if i == "struct" || i == "union" => panic!("GuiEnum can only be derived for enums"),
+            proc_macro2::TokenTree::Punct(p) if p.as_char() == '<' && name.is_some() => panic!("GuiEnum can not be derived for generic enums"),
+            proc_macro2::TokenTree::Group(g) if g.delimiter() == proc_macro2::Delimiter::Brace && name.is_some() => {
+                body = Some(g);
+            },
+            _ => {},
+        }
+    }
+
+    let name = name.expect("GuiEnum can only be derived for enums");
+    let body = body.expect("expected the variants of the enum");
+
+    let mut variants = vec![];
+    let mut labels = vec![];
+    let mut label = None;
+    let mut expect_variant = true;
+    let mut variant_tokens = body.stream().into_iter().peekable();
+    while let Some(tt) = variant_tokens.next() {
+        match tt {
+            proc_macro2::TokenTree::Punct(p) if p.as_char() == '#' => {
+                if let Some(proc_macro2::TokenTree::Group(attribute)) = variant_tokens.next() {
+                    if let Some(l) = gui_label(attribute.stream()) {
+                        label = Some(l);
+                    }
+                }
+            },
+            proc_macro2::TokenTree::Punct(p) if p.as_char() == ',' => expect_variant = true,
+            proc_macro2::TokenTree::Ident(i) if expect_variant => {
+                if let Some(proc_macro2::TokenTree::Group(_)) = variant_tokens.peek() {
+                    let message = format!("GuiEnum can only be derived for enums without fields, variant {} has fields", i);
+                    return quote::quote!(compile_error!(#message);).into();
+                }
+                labels.push(label.take().unwrap_or_else(|| proc_macro2::Literal::string(&i.to_string())));
+                variants.push(i);
+                expect_variant = false;
+            },
+            //skips explicit discriminants
+            _ => {},
+        }
+    }
+
+    //a Combo or RadioGroup always has a selected variant
+    if variants.is_empty() {
+        return quote::quote!(compile_error!("GuiEnum can not be derived for enums without variants");).into();
+    }
+
+    quote::quote! {
+        impl ::rust_gui::GuiEnum for #name {
+            fn variants() -> &'static [Self] {
+                &[#(#name::#variants),*]
+            }
+
+            fn label(&self) -> &'static str {
+                match self {
+                    #(#name::#variants => #labels,)*
+                }
+            }
+        }
+    }.into()
+}
+
+/// Returns the label of the attribute content `gui(label = "...")`.
+fn gui_label(attribute: proc_macro2::TokenStream) -> Option<proc_macro2::Literal> {
+    let mut tokens = attribute.into_iter();
+    match tokens.next() {
+        Some(proc_macro2::TokenTree::Ident(i)) if i == "gui" => {},
+        _ => return None,
+    }
+    let arguments = match tokens.next() {
+        Some(proc_macro2::TokenTree::Group(g)) => g.stream().into_iter().collect::<Vec<_>>(),
+        _ => panic!("expected #[gui(label = \"...\")]"),
+    };
+    match arguments.as_slice() {
+        [proc_macro2::TokenTree::Ident(key), proc_macro2::TokenTree::Punct(eq), proc_macro2::TokenTree::Literal(value)]
+            if key == "label" && eq.as_char() == '=' =>
+        {
+            Some(string_literal(value))
+        },
+        _ => panic!("expected #[gui(label = \"...\")]"),
+    }
+}
+
+#[proc_macro_derive(TableRow, attributes(table))]
+///Implements the trait TableRow of rust-gui for a struct with named fields so a Vec of it can be shown with Table::from_rows.
+/// 
+/// Every field is a column showing the field with Display, sorted with PartialOrd. Options of `#[table(...)]`:
+/// 
+/// header = "...": the column header instead of the field name
+/// 
+/// format = "...": format string for the field, e.g. "{:.1} MB"
+/// 
+/// width = 80: fixed column width in pixels
+/// 
+/// sort_key = "field": sort the column by another field
+/// 
+/// skip: no column for this field
+/// 
+/// Example:
+/// #[derive(TableRow)]
+/// struct Job { name: String, #[table(header = "size", format = "{:.1} MB", sort_key = "bytes")] megabytes: f32, #[table(skip)] bytes: u64 }
+pub fn derive_table_row(input: TokenStream) -> TokenStream {
+    let mut tokens = proc_macro2::TokenStream::from(input).into_iter();
+    let mut name = None;
+    let mut body = None;
+
+    while let Some(tt) = tokens.next() {
+        match tt {
+            proc_macro2::TokenTree::Ident(i) if i == "struct" => {
+                match tokens.next() {
+                    Some(proc_macro2::TokenTree::Ident(i)) => name = Some(i),
+                    _ => panic!("expected the name of the struct"),
+                }
+            },
+            proc_macro2::TokenTree::Ident(i) if i == "enum" || i == "union" => panic!("TableRow can only be derived for structs"),
+            proc_macro2::TokenTree::Punct(p) if p.as_char() == '<' && name.is_some() => panic!("TableRow can not be derived for generic structs"),
+            proc_macro2::TokenTree::Group(g) if g.delimiter() == proc_macro2::Delimiter::Brace && name.is_some() => {
+                body = Some(g);
+            },
+            proc_macro2::TokenTree::Group(g) if g.delimiter() == proc_macro2::Delimiter::Parenthesis && name.is_some() => {
+                panic!("TableRow can only be derived for structs with named fields")
+            },
+            _ => {},
+        }
+    }
+
+    let name = name.expect("TableRow can only be derived for structs");
+    let body = body.expect("TableRow can only be derived for structs with named fields");
+
+    let mut columns = vec![];
+    let mut cells = vec![];
+    let mut comparisons = vec![];
+    for field in table_fields(body.stream()) {
+        if field.skip {
+            continue;
+        }
+        let index = columns.len();
+        let ident = &field.ident;
+
+        let header = field.header.unwrap_or_else(|| proc_macro2::Literal::string(&ident.to_string()));
+        let width = match field.width {
+            Some(width) => quote::quote!(.width((#width) as f32)),
+            None => quote::quote!(),
+        };
+        columns.push(quote::quote!(::rust_gui::Column::new(#header) #width));
+
+        cells.push(match field.format {
+            Some(format) => quote::quote!(#index => format!(#format, self.#ident)),
+            None => quote::quote!(#index => self.#ident.to_string()),
+        });
+
+        let key = field.sort_key.unwrap_or_else(|| ident.clone());
+        comparisons.push(quote::quote! {
+            #index => self.#key.partial_cmp(&other.#key).unwrap_or(::std::cmp::Ordering::Equal)
+        });
+    }
+
+    quote::quote! {
+        impl ::rust_gui::TableRow for #name {
+            fn columns() -> Vec<::rust_gui::Column> {
+                vec![#(#columns),*]
+            }
+
+            fn cell(&self, column: usize) -> String {
+                match column {
+                    #(#cells,)*
+                    _ => String::new(),
+                }
+            }
+
+            fn compare(&self, other: &Self, column: usize) -> ::std::cmp::Ordering {
+                match column {
+                    #(#comparisons,)*
+                    _ => ::std::cmp::Ordering::Equal,
+                }
+            }
+        }
+    }.into()
+}
+
+struct TableField {
+    ident: proc_macro2::Ident,
+    header: Option<proc_macro2::Literal>,
+    format: Option<proc_macro2::Literal>,
+    width: Option<proc_macro2::Literal>,
+    sort_key: Option<proc_macro2::Ident>,
+    skip: bool,
+}
+
+/// Splits the content of the braces of a struct into its fields and their #[table(...)] options.
+fn table_fields(body: proc_macro2::TokenStream) -> Vec<TableField> {
+    let mut fields = vec![];
+    let mut field: Option<TableField> = None;
+    let mut options = vec![];
+    let mut in_type = false;
+    let mut angle_depth = 0;
+
+    let mut tokens = body.into_iter().peekable();
+    while let Some(tt) = tokens.next() {
+        if in_type {
+            //the type ends at the first comma outside of generic arguments
+            match tt {
+                proc_macro2::TokenTree::Punct(p) if p.as_char() == '<' => angle_depth += 1,
+                proc_macro2::TokenTree::Punct(p) if p.as_char() == '>' && angle_depth > 0 => angle_depth -= 1,
+                proc_macro2::TokenTree::Punct(p) if p.as_char() == ',' && angle_depth == 0 => {
+                    in_type = false;
+                    fields.extend(field.take());
+                },
+                _ => {},
+            }
+            continue;
+        }
+
+        match tt {
+            proc_macro2::TokenTree::Punct(p) if p.as_char() == '#' => {
+                if let Some(proc_macro2::TokenTree::Group(attribute)) = tokens.next() {
+                    let mut attribute = attribute.stream().into_iter();
+                    if let (Some(proc_macro2::TokenTree::Ident(i)), Some(proc_macro2::TokenTree::Group(g))) = (attribute.next(), attribute.next()) {
+                        if i == "table" {
+                            options.push(g.stream());
+                        }
+                    }
+                }
+            },
+            //visibility like pub or pub(crate)
+            proc_macro2::TokenTree::Ident(i) if i == "pub" => {
+                if let Some(proc_macro2::TokenTree::Group(_)) = tokens.peek() {
+                    tokens.next();
+                }
+            },
+            proc_macro2::TokenTree::Ident(ident) => {
+                let mut table_field = TableField { ident, header: None, format: None, width: None, sort_key: None, skip: false };
+                for option in options.drain(..) {
+                    table_options(option, &mut table_field);
+                }
+                field = Some(table_field);
+            },
+            proc_macro2::TokenTree::Punct(p) if p.as_char() == ':' => in_type = true,
+            _ => {},
+        }
+    }
+    fields.extend(field.take());
+    fields
+}
+
+fn table_options(options: proc_macro2::TokenStream, field: &mut TableField) {
+    let options: Vec<proc_macro2::TokenTree> = options.into_iter().collect();
+    for option in options.split(|tt| matches!(tt, proc_macro2::TokenTree::Punct(p) if p.as_char() == ',')) {
+        match option {
+            [] => {},
+            [proc_macro2::TokenTree::Ident(key)] if key == "skip" => field.skip = true,
+            [proc_macro2::TokenTree::Ident(key), proc_macro2::TokenTree::Punct(eq), proc_macro2::TokenTree::Literal(value)] if eq.as_char() == '=' => {
+                match key.to_string().as_str() {
+                    "header" => field.header = Some(string_literal(value)),
+                    "format" => field.format = Some(value.clone()),
+                    "width" => field.width = Some(value.clone()),
+                    "sort_key" => field.sort_key = Some(field_name(value)),
+                    _ => panic!("unknown table option {}, expected header, format, width, sort_key or skip", key),
+                }
+            },
+            _ => panic!("expected #[table(header = \"...\", format = \"...\", width = ..., sort_key = \"...\", skip)]"),
+        }
+    }
+}
+
+/// Checks that the literal is a string literal, raw strings included. The literal is emitted unchanged,
+/// so the compiler resolves its escapes.
+fn string_literal(literal: &proc_macro2::Literal) -> proc_macro2::Literal {
+    let value = literal.to_string();
+    if !(value.starts_with('"') || value.starts_with("r\"") || value.starts_with("r#")) {
+        panic!("expected a string literal, found {}", value);
+    }
+    literal.clone()
+}
+
+/// The field named by a string literal like `sort_key = "bytes"`, names contain no escapes.
+fn field_name(literal: &proc_macro2::Literal) -> proc_macro2::Ident {
+    let value = string_literal(literal).to_string();
+    quote::format_ident!("{}", value.trim_start_matches('r').trim_matches('#').trim_matches('"'))
+}
@@ -2,6 +2,7 @@ use std::{path::Path, process::Command};
 
 fn main() {
     println!("cargo:rerun-if-changed=src/gui/gui_lib.cpp");
+    println!("cargo:rerun-if-changed=src/gui/implot_lib.cpp");
     let imgui_str = format!("{}{}", std::env::var("OUT_DIR").unwrap(), "/imgui/");
     let imgui_path = imgui_str.as_str();
 
@@ -24,7 +25,8 @@ fn main() {
         .expect("pulling Dear ImGui failed");
 
     //compile Dear ImGui + gui_lib.cpp
-    cc::Build::new()
+    let mut build = cc::Build::new();
+    build
         .cpp(true)
         .include(format!("{}{}", imgui_path, ""))
         .include(format!("{}backends", imgui_path))
@@ -37,8 +39,36 @@ fn main() {
         .file(format!("{}backends/imgui_impl_opengl3.cpp", imgui_path))
         .file(format!("{}backends/imgui_impl_glfw.cpp", imgui_path))
         .file(format!("{}backends/imgui_impl_glfw.cpp", imgui_path))
-        .file(format!("{}imgui_demo.cpp", imgui_path))
-        .compile("gui_lib");
+        .file(format!("{}imgui_demo.cpp", imgui_path));
+
+    //clone and compile ImPlot if the feature implot is enabled
+    if std::env::var("CARGO_FEATURE_IMPLOT").is_ok() {
+        let implot_str = format!("{}{}", std::env::var("OUT_DIR").unwrap(), "/implot/");
+        let implot_path = implot_str.as_str();
+        if !Path::new(implot_path).exists() {
+            Command::new("git")
+                .args([
+                    "clone",
+                    "https://github.com/epezent/implot.git",
+                    implot_path,
+                ])
+                .status()
+                .expect("cloning ImPlot failed");
+        }
+        Command::new("git")
+            .args(["-C", implot_path, "pull"])
+            .status()
+            .expect("pulling ImPlot failed");
+
+        build
+            .define("RUST_GUI_IMPLOT", None)
+            .include(implot_path)
+            .file("src/gui/implot_lib.cpp")
+            .file(format!("{}implot.cpp", implot_path))
+            .file(format!("{}implot_items.cpp", implot_path));
+    }
+
+    build.compile("gui_lib");
 
     //link everything
     println!("cargo:rustc-link-lib=glfw3");
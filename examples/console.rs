@@ -0,0 +1,32 @@
+use rust_gui::*;
+
+fn main() {
+    let gui = Gui::new("console")
+        .window(
+            Window::new("debug shell").add(
+                Console::new("shell")
+                    .command("echo", |_: &Gui, args: &[&str]| args.join(" "))
+                    .command("add", |_: &Gui, args: &[&str]| {
+                        let sum: Result<f64, _> = args.iter().map(|arg| arg.parse::<f64>()).sum();
+                        match sum {
+                            Ok(sum) => sum.to_string(),
+                            Err(e) => format!("error: {}", e),
+                        }
+                    })
+                    .command("demo", |gui: &Gui, _: &[&str]| {
+                        let state = *gui.show_demo_window.blocking_read();
+                        *gui.show_demo_window.blocking_write() = !state;
+                        String::new()
+                    }),
+            ),
+        )
+        .build();
+
+    let receiver = gui.start();
+    gui.get_widget::<Console>(0, 0)
+        .print("type 'help' to list the commands, tab completes them");
+
+    while gui.is_running() {
+        receiver.recv().unwrap();
+    }
+}
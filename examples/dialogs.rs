@@ -0,0 +1,29 @@
+use std::thread;
+
+use rust_gui::*;
+
+fn main() {
+    let gui = Gui::new("dialogs")
+        .window(Window::new("status").add(Text::new("the worker thread asks questions")))
+        .build();
+    let receiver = gui.start();
+
+    let worker_gui = gui.clone();
+    thread::spawn(move || {
+        let gui = worker_gui;
+        let name = gui
+            .prompt("your name")
+            .unwrap_or_else(|| String::from("stranger"));
+        if gui.confirm("greeting", &format!("greet {}?", name)) {
+            gui.blocking_message_box("greeting", &format!("hello {}!", name));
+        }
+        gui.set::<Text, &str>(0, 0, "done");
+    });
+
+    //the worker keeps a clone of the gui, so stop once the GUI thread hung up
+    while gui.is_running() {
+        if receiver.recv().is_err() {
+            break;
+        }
+    }
+}
@@ -1,12 +1,20 @@
 use rust_gui::*;
 
+#[derive(Clone, Copy, PartialEq, GuiEnum)]
+enum Quality {
+    Low,
+    Medium,
+    #[gui(label = "High (slow)")]
+    High,
+}
+
 fn main() {
     let gui = Gui::new("example");
 
     let gui = gui
         .window(
             Window::new("window label")
-                .add(Button::new("button label").set_callback(|_: &Gui| println!("pressed!")))
+                .add(Button::new("button label").set_callback(|_: &Gui| println!("pressed!")).tooltip("prints to the console"))
                 .same_line(Button::new("B"))
                 .add(TreeNode::new("collapsable stuff")
                     .add(Button::new("button").set_callback(|_: &Gui| println!("button in tree node")))
@@ -15,21 +23,58 @@ fn main() {
                 )
                 .add(SliderInt::new("i32"))
                 .add(SliderFloat::new("f32"))
-                .add(InputColor::new("choose a color"))
-                .add(InputText::new("write some Text!"))
+                .add(SliderFloat::new("gain").range(0.001, 10.0).logarithmic().format("%.3f x"))
+                .same_line(HelpMarker::new("logarithmic scale, ctrl+click to type a value"))
+                .add(SliderAngle::new("angle").range(0.0, 180.0))
+                .add(InputInt::new("exact i32").clamp(0, 1000))
+                .add(InputFloat3::new("position").format("%.2f"))
+                .add(InputColor::new("choose a color").alpha().display_hex())
+                .add(ColorButton::new("accent", Color::from_hex("#3D85C6").unwrap()))
+                .add(InputText::new("write some Text!").item_width(200.0))
+                .add(Combo::new("fruit").items(["apple", "banana", "cherry"]).filter())
+                .add(Combo::<Quality>::new("quality"))
+                .add(RadioGroup::<Quality>::new("render quality").horizontal().set_callback(|gui: &Gui| {
+                    let quality: Quality = gui.get::<RadioGroup<Quality>, Quality>(0, 0);
+                    println!("render quality: {}", quality.label());
+                }))
+                .add(ListBox::new("files").items(["a.txt", "b.txt", "c.txt", "d.txt"]).multi_select())
         )
         .window(
             Window::new("drag me!").add(Checkbox::new("show demo window").set_callback(|gui: &Gui| {
                 let state = *gui.show_demo_window.blocking_read();
                 *gui.show_demo_window.blocking_write() = !state;
             }))
-            .add(Text::new("just some text")),
+            .add(Text::new("just some text"))
+            .add(Separator::new())
+            .add(Indent::new(0.0).add(Group::new()
+                .add(Text::new("grouped and indented"))
+                .add(Text::new("hover both lines"))
+                .tooltip("one tooltip for the whole group")
+            ))
+            .add(Spacing::new())
+            .add(Columns::new("form", 2).column_width(0, 80.0)
+                .add(Text::new("name").align_right())
+                .add(InputText::new("##name").item_width(-f32::MIN_POSITIVE))
+                .add(Text::new("port").align_right())
+                .add(InputInt::new("##port").item_width(-f32::MIN_POSITIVE))
+            )
+            .add(Button::new("save").center())
+            .add(PlotLines::new("sine").capacity(200).scale(-1.0, 1.0).size(0.0, 60.0)),
         );
 
     let gui = gui.build(); //get a handle to the gui which can be shared between different threads
 
     let receiver = gui.start(); //start the rendering loop of the gui in its own thread
 
+    //samples can be pushed from any thread
+    let plot = gui.get_widget::<PlotLines>(1, 0);
+    std::thread::spawn(move || {
+        for i in 0.. {
+            plot.push((i as f32 * 0.1).sin());
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+    });
+
     while gui.is_running() {
         receiver.recv().unwrap(); //wait until one rendering loop has finished so the input got updated.
 
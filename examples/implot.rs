@@ -0,0 +1,41 @@
+use std::{f64::consts::PI, sync::Arc, thread, time::Duration};
+
+use rust_gui::*;
+use tokio::sync::RwLock;
+
+fn main() {
+    let sine: Points = Arc::new(RwLock::new(vec![]));
+    let samples: Points = Arc::new(RwLock::new(
+        (0..20).map(|i| [i as f64, (i % 7) as f64]).collect(),
+    ));
+
+    let gui = Gui::new("implot").window(
+        Window::new("charts")
+            .add(
+                Plot::new("signal")
+                    .x_label("time")
+                    .x_format("%.1f s")
+                    .y_label("amplitude")
+                    .y_limits(-1.5, 1.5)
+                    .series(Series::line("sine", sine.clone()))
+                    .series(Series::scatter("samples", samples.clone())),
+            )
+            .add(Plot::new("histogram").series(Series::bars("count", samples, 0.5))),
+    );
+
+    let gui = gui.build();
+    let receiver = gui.start();
+
+    //the data is produced in another thread, the plot shows the current state every frame
+    thread::spawn(move || {
+        for i in 0.. {
+            let t = i as f64 * 0.05;
+            sine.blocking_write().push([t, (2.0 * PI * 0.5 * t).sin()]);
+            thread::sleep(Duration::from_millis(50));
+        }
+    });
+
+    while gui.is_running() {
+        receiver.recv().unwrap();
+    }
+}
@@ -3,15 +3,24 @@ use std::path::Path;
 use rust_gui::*;
 use walkdir::WalkDir;
 
-fn get_size(dir: &str) -> u64 {
-    let total_size = WalkDir::new(dir)
+fn get_size(dir: &str, progress: &Progress) -> u64 {
+    progress.set_indeterminate();
+    progress.set_overlay("collecting files...");
+    let files: Vec<_> = WalkDir::new(dir)
         .min_depth(1)
         .max_depth(3)
         .into_iter()
         .filter_map(|entry| entry.ok())
-        .filter_map(|entry| entry.metadata().ok())
-        .filter(|metadata| metadata.is_file())
-        .fold(0, |acc, m| acc + m.len());
+        .filter(|entry| entry.file_type().is_file())
+        .collect();
+
+    progress.clear_overlay();
+    let mut total_size = 0;
+    for (i, file) in files.iter().enumerate() {
+        total_size += file.metadata().map_or(0, |metadata| metadata.len());
+        progress.set_steps(i + 1, files.len());
+    }
+    progress.set(1.0);
 
     total_size
 }
@@ -25,6 +34,7 @@ fn main() {
             ))
             .add(Button::new("get Size:"))
             .same_line(InputText::new("###1"))
+            .add(ProgressBar::new())
             .add(Text::new("input a Directory...")),
     );
     let gui = gui.build();
@@ -35,16 +45,23 @@ fn main() {
         rec.recv().unwrap(); //wait until one rendering loop has finished so the input got updated.
 
         if gui.get::<Button, bool>(0, 0) {
-            let text;
             let input: String = gui.get::<InputText, String>(0, 0);
             if Path::new(&input).exists() {
                 gui.set::<Text, String>(0, 1, String::from("calculating..."));
-                let byte_size: f32 = get_size(input.as_str()) as f32 / (1024.0 * 1024.0);
-                text = format!("the directory is {} MB large.", byte_size);
+
+                //the size is calculated in its own thread so the gui stays responsive
+                let result_gui = gui.clone();
+                gui.get_widget::<ProgressBar>(0, 0).spawn(
+                    move |progress| get_size(input.as_str(), &progress),
+                    move |size| {
+                        let byte_size: f32 = size as f32 / (1024.0 * 1024.0);
+                        let text = format!("the directory is {} MB large.", byte_size);
+                        result_gui.set::<Text, String>(0, 1, text);
+                    },
+                );
             } else {
-                text = String::from("directory not found");
+                gui.set::<Text, String>(0, 1, String::from("directory not found"));
             }
-            gui.set::<Text, String>(0, 1, text);
         }
     }
 }
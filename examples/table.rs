@@ -0,0 +1,103 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use rust_gui::*;
+use tokio::sync::RwLock;
+
+#[derive(TableRow)]
+struct Job {
+    #[table(width = 40)]
+    id: u32,
+    pub name: String,
+    #[table(header = "progress", format = "{:.0} %")]
+    percent: f32,
+    #[table(header = "size", format = "{:.1} MB", sort_key = "bytes")]
+    megabytes: f64,
+    #[table(skip)]
+    bytes: u64,
+    #[table(skip)]
+    _log: Vec<String>,
+}
+
+impl Job {
+    fn new(id: u32, name: &str, percent: f32, bytes: u64) -> Self {
+        Job {
+            id,
+            name: String::from(name),
+            percent,
+            megabytes: bytes as f64 / (1024.0 * 1024.0),
+            bytes,
+            _log: vec![],
+        }
+    }
+}
+
+/// A million rows which are computed when they become visible instead of being stored.
+struct Numbers {
+    descending: AtomicBool,
+}
+
+impl TableData for Numbers {
+    fn row_count(&self) -> usize {
+        1_000_000
+    }
+
+    fn cell(&self, row: usize, column: usize) -> String {
+        let n = if self.descending.load(Ordering::Relaxed) {
+            self.row_count() - row
+        } else {
+            row + 1
+        } as u64;
+        match column {
+            0 => n.to_string(),
+            1 => (n * n).to_string(),
+            _ => format!("{:#x}", n),
+        }
+    }
+
+    //every column is ordered like the number itself
+    fn sort(&self, specs: &[SortSpec]) {
+        if let Some(spec) = specs.first() {
+            self.descending.store(!spec.ascending, Ordering::Relaxed);
+        }
+    }
+}
+
+fn main() {
+    let numbers = Numbers {
+        descending: AtomicBool::new(false),
+    };
+
+    let jobs = Arc::new(RwLock::new(vec![
+        Job::new(1, "backup", 40.0, 3_500_000_000),
+        Job::new(2, "render", 95.5, 120_000_000),
+        Job::new(3, "upload", 0.0, 750_000),
+    ]));
+
+    let gui = Gui::new("table")
+        .window(
+            Window::new("numbers").add(
+                Table::new("numbers", numbers)
+                    .column(Column::new("n").width(100.0).no_hide().default_sort())
+                    .column(Column::new("n²"))
+                    .column(Column::new("hex").hidden()),
+            ),
+        )
+        .window(Window::new("jobs").add(Table::from_rows("jobs", jobs.clone()).sort_multi()));
+
+    let gui = gui.build();
+    let receiver = gui.start();
+
+    while gui.is_running() {
+        receiver.recv().unwrap();
+
+        //the table shows changes to the rows in the next frame
+        for job in jobs.blocking_write().iter_mut() {
+            job.percent = (job.percent + 0.05) % 100.0;
+        }
+        //keeps the rows sorted if the table is sorted by the changing column
+        gui.get_widget::<Table>(1, 0).resort();
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rust_gui::*;
+
+static DOCUMENTS: AtomicUsize = AtomicUsize::new(0);
+
+fn document(name: &str) -> TabItem {
+    let label = format!("{}##{}", name, DOCUMENTS.fetch_add(1, Ordering::Relaxed));
+    let name = String::from(name);
+    TabItem::new(&label)
+        .closable()
+        .set_close_callback(move |_gui: &Gui| println!("closed {}", name))
+        .add(InputText::new("##text").multiline(-1.0, -1.0))
+}
+
+fn main() {
+    let menu_bar = MainMenuBar::new()
+        .add(
+            Menu::new("File")
+                .add(
+                    MenuItem::new("New")
+                        .shortcut("Ctrl+N")
+                        .set_callback(|gui: &Gui| {
+                            let tabs = gui.get_widget::<TabBar>(0, 0);
+                            tabs.open_tab(document("untitled"));
+                        }),
+                )
+                .add(
+                    MenuItem::new("Quit")
+                        .shortcut("Ctrl+Q")
+                        .set_callback(|gui: &Gui| gui.open_popup("quit?")),
+                ),
+        )
+        .add(
+            Menu::new("View").add(
+                MenuItem::new("Demo window")
+                    .shortcut("F1")
+                    .checked(false)
+                    .set_callback(|gui: &Gui| {
+                        let mut show = gui.show_demo_window.blocking_write();
+                        *show = !*show;
+                    }),
+            ),
+        );
+
+    let gui = Gui::new("tabs").main_menu_bar(menu_bar).window(
+        Window::new("editor")
+            .add(Button::new("new document").set_callback(|gui: &Gui| {
+                let tabs = gui.get_widget::<TabBar>(0, 0);
+                tabs.open_tab(document("untitled"));
+            }))
+            .same_line(Button::new("show welcome").set_callback(|gui: &Gui| {
+                let tabs = gui.get_widget::<TabBar>(0, 0);
+                if let Some(welcome) = tabs.tab("welcome") {
+                    welcome.reopen();
+                    welcome.select();
+                }
+            }))
+            .add(
+                TabBar::new("documents")
+                    .reorderable()
+                    .auto_select_new_tabs()
+                    .add(
+                        TabItem::new("welcome")
+                            .closable()
+                            .add(Text::new("open a document with the button above")),
+                    ),
+            )
+            .add(ContextMenu::window("editor menu").add(
+                MenuItem::new("New document").set_callback(|gui: &Gui| {
+                    let tabs = gui.get_widget::<TabBar>(0, 0);
+                    tabs.open_tab(document("untitled"));
+                }),
+            ))
+            .add(
+                PopupModal::new("quit?")
+                    .add(Text::new("unsaved documents will be lost"))
+                    .button("quit")
+                    .button("cancel")
+                    .set_result_callback(|_gui: &Gui, result| {
+                        if result == Some("quit") {
+                            std::process::exit(0);
+                        }
+                    }),
+            ),
+    );
+
+    let gui = gui.build();
+    let receiver = gui.start();
+
+    while gui.is_running() {
+        receiver.recv().unwrap();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::SortSpec;
 use std::ffi::c_void;
 
 #[repr(C)]
@@ -9,6 +10,24 @@ pub struct ImGui_Vec4 {
     pub w: f32,
 }
 
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ImGui_Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl From<[f32; 4]> for ImGui_Vec4 {
+    fn from(value: [f32; 4]) -> Self {
+        ImGui_Vec4 {
+            x: value[0],
+            y: value[1],
+            z: value[2],
+            w: value[3],
+        }
+    }
+}
+
 #[allow(unused)]
 extern "C" {
     pub fn init_gui(window_label: *const u8) -> GUI_handle<'static>;
@@ -16,20 +35,200 @@ extern "C" {
     pub fn close_window(window: &c_void) -> bool;
 
     pub fn start_frame();
+    pub fn ImGui_DockSpaceOverViewport();
     pub fn end_frame(window: &'static c_void, io: &'static c_void, color: ImGui_Vec4);
     pub fn show_demo_window();
 
     //to be able to use the derive macro ImGuiGlue the functions have to have the name ImGui_<struct name>.
     pub fn ImGui_Checkbox(label: *const u8, value: &bool);
-    pub fn ImGui_Text(text: *const u8);
+    pub fn ImGui_Text(text: *const u8, len: usize);
+    pub fn ImGui_TextColored(text: *const u8, len: usize, color: ImGui_Vec4);
+    pub fn ImGui_TextWrapped(text: *const u8, len: usize, wrap_width: f32);
+    pub fn ImGui_TextDisabled(text: *const u8, len: usize);
+    pub fn ImGui_BulletText(text: *const u8, len: usize);
+    pub fn ImGui_SeparatorText(text: *const u8, len: usize);
+    pub fn ImGui_LabelText(label: *const u8, text: *const u8, len: usize);
     pub fn ImGui_Button(text: *const u8, value: &bool);
     pub fn ImGui_Begin(name: *const u8, close: &bool, flags: i32);
     pub fn ImGui_End();
-    pub fn ImGui_ColorEdit3(label: *const u8, value: &ImGui_Vec4); //alias ImGui_Color
+    pub fn ImGui_BeginCombo(label: *const u8, preview: *const u8, preview_len: usize) -> bool;
+    pub fn ImGui_EndCombo();
+    pub fn ImGui_BeginListBox(label: *const u8, size: ImGui_Vec2) -> bool;
+    pub fn ImGui_EndListBox();
+    pub fn ImGui_Selectable(
+        text: *const u8,
+        len: usize,
+        selected: bool,
+        flags: i32,
+        size: ImGui_Vec2,
+    ) -> i32;
+    pub fn ImGui_GetKeyMods() -> i32;
+    pub fn ImGui_SetItemDefaultFocus();
+    pub fn ImGui_InputFilter(id: *const u8, buffer: *mut u8, buffer_size: usize) -> bool;
+    pub fn ImGui_RadioButton(text: *const u8, len: usize, active: bool) -> bool;
+    pub fn ImGui_PushID(id: i32);
+    pub fn ImGui_PushStrID(id: *const u8);
+    pub fn ImGui_PopID();
+    pub fn ImGui_ProgressBar(
+        fraction: f32,
+        size: ImGui_Vec2,
+        overlay: *const u8,
+        overlay_len: usize,
+        indeterminate: bool,
+    );
+    pub fn ImGui_PlotSamples(
+        label: *const u8,
+        values: *const f32,
+        count: usize,
+        offset: usize,
+        overlay: *const u8,
+        overlay_len: usize,
+        scale_min: f32,
+        scale_max: f32,
+        size: ImGui_Vec2,
+        histogram: bool,
+    );
+    pub fn ImGui_BeginTable(id: *const u8, columns: i32, flags: i32, size: ImGui_Vec2) -> bool;
+    pub fn ImGui_EndTable();
+    pub fn ImGui_TableSetupColumn(label: *const u8, flags: i32, width: f32);
+    pub fn ImGui_TableSetupScrollFreeze(columns: i32, rows: i32);
+    pub fn ImGui_TableHeadersRow();
+    pub fn ImGui_TableNextRow();
+    pub fn ImGui_TableSetColumnIndex(column: i32) -> bool;
+    pub fn ImGui_TableSortSpecs(specs: *mut SortSpec, capacity: usize) -> i32;
+    pub fn ImGui_ListClipperBegin(count: usize) -> *mut c_void;
+    pub fn ImGui_ListClipperStep(clipper: *mut c_void, start: &mut usize, end: &mut usize) -> bool;
+    pub fn ImGui_ListClipperEnd(clipper: *mut c_void);
+    pub fn ImGui_BeginMainMenuBar() -> bool;
+    pub fn ImGui_EndMainMenuBar();
+    pub fn ImGui_BeginMenuBar() -> bool;
+    pub fn ImGui_EndMenuBar();
+    pub fn ImGui_BeginMenu(label: *const u8, enabled: bool) -> bool;
+    pub fn ImGui_EndMenu();
+    pub fn ImGui_MenuItem(
+        label: *const u8,
+        shortcut: *const u8,
+        checked: bool,
+        enabled: bool,
+    ) -> bool;
+    pub fn ImGui_IsShortcutPressed(
+        mods: i32,
+        key: *const u8,
+        key_len: usize,
+        focused_window_only: bool,
+    ) -> bool;
+    pub fn ImGui_BeginItemTooltip() -> bool;
+    pub fn ImGui_EndTooltip();
+    pub fn ImGui_OpenPopup(id: *const u8);
+    pub fn ImGui_BeginPopup(id: *const u8) -> bool;
+    pub fn ImGui_BeginPopupModal(title: *const u8, open: *mut bool) -> bool;
+    pub fn ImGui_BeginPopupContextItem(id: *const u8) -> bool;
+    pub fn ImGui_BeginPopupContextWindow(id: *const u8) -> bool;
+    pub fn ImGui_EndPopup();
+    pub fn ImGui_CloseCurrentPopup();
+    pub fn ImGui_BeginTabBar(id: *const u8, flags: i32) -> bool;
+    pub fn ImGui_EndTabBar();
+    pub fn ImGui_BeginTabItem(label: *const u8, open: *mut bool, flags: i32) -> bool;
+    pub fn ImGui_EndTabItem();
+    pub fn ImGui_ColorEdit(label: *const u8, value: &mut ImGui_Vec4, flags: i32) -> bool;
+    pub fn ImGui_ColorButton(
+        label: *const u8,
+        color: ImGui_Vec4,
+        flags: i32,
+        size: ImGui_Vec2,
+    ) -> bool;
     pub fn ImGui_SameLine(offset_from_start_x: f32, spacing: f32);
-    pub fn ImGui_SliderInt(label: *const u8, value: &i32, min_val: i32, max_val: i32);
-    pub fn ImGui_SliderFloat(label: *const u8, value: &f32, min_val: f32, max_val: f32);
-    pub fn ImGui_InputText(label: *const u8, value: *const u8, buffer_size: i32, flags: i32);
+    pub fn ImGui_SliderInt(
+        label: *const u8,
+        value: &i32,
+        min_val: i32,
+        max_val: i32,
+        format: *const u8,
+        flags: i32,
+        vertical_size: ImGui_Vec2,
+    );
+    pub fn ImGui_SliderFloat(
+        label: *const u8,
+        value: &f32,
+        min_val: f32,
+        max_val: f32,
+        format: *const u8,
+        flags: i32,
+        vertical_size: ImGui_Vec2,
+    );
+    pub fn ImGui_SliderAngle(
+        label: *const u8,
+        value: &f32,
+        min_degrees: f32,
+        max_degrees: f32,
+        format: *const u8,
+        flags: i32,
+    );
+    pub fn ImGui_InputText(
+        label: *const u8,
+        hint: *const u8,
+        buffer: *mut u8,
+        buffer_size: usize,
+        flags: i32,
+        multiline: bool,
+        width: f32,
+        height: f32,
+        replace_active: bool,
+        handler: *mut InputTextHandler,
+    ) -> i32;
+    pub fn ImGui_Separator();
+    pub fn ImGui_Spacing();
+    pub fn ImGui_Indent(indent_w: f32);
+    pub fn ImGui_Unindent(indent_w: f32);
+    pub fn ImGui_Bullet();
+    pub fn ImGui_NewLine();
+    pub fn ImGui_Dummy(size: ImGui_Vec2);
+    pub fn ImGui_BeginGroup();
+    pub fn ImGui_EndGroup();
+    pub fn ImGui_SetNextItemWidth(width: f32);
+    pub fn ImGui_PushItemWidth(width: f32);
+    pub fn ImGui_PopItemWidth();
+    pub fn ImGui_AlignNextItem(width: f32, alignment: f32);
+    pub fn ImGui_GetItemRectWidth() -> f32;
+    pub fn ImGui_BeginChild(id: *const u8, width: f32, height: f32, border: bool) -> bool;
+    pub fn ImGui_EndChild();
+    pub fn ImGui_GetFrameHeightWithSpacing() -> f32;
+    pub fn ImGui_GetScrollY() -> f32;
+    pub fn ImGui_GetScrollMaxY() -> f32;
+    pub fn ImGui_SetScrollHereY(center_y_ratio: f32);
+    pub fn ImGui_SetKeyboardFocusHere(offset: i32);
+    pub fn ImGui_PushHeadingFont(level: i32);
+    pub fn ImGui_PopFont();
+    pub fn ImGui_MarkdownText(text: *const u8, len: usize, style: i32, same_line: bool) -> bool;
+    pub fn ImGui_MarkdownCodeBlock(text: *const u8, len: usize);
+    pub fn ImGui_InputScalarN(
+        label: *const u8,
+        data_type: i32,
+        data: *mut c_void,
+        components: i32,
+        step: *const c_void,
+        step_fast: *const c_void,
+        format: *const u8,
+    ) -> bool;
+    pub fn ImGui_DragScalarN(
+        label: *const u8,
+        data_type: i32,
+        data: *mut c_void,
+        components: i32,
+        speed: f32,
+        min: *const c_void,
+        max: *const c_void,
+        format: *const u8,
+    ) -> bool;
+    pub fn ImGui_DragRange2(
+        label: *const u8,
+        data_type: i32,
+        data: *mut c_void,
+        speed: f32,
+        min: *const c_void,
+        max: *const c_void,
+        format: *const u8,
+    ) -> bool;
     pub fn ImGUI_TreeNodeEx(label: *const u8, flags: i32) -> bool;
     pub fn ImGui__TreePop();
 }
@@ -41,6 +240,146 @@ pub struct GUI_handle<'a> {
     pub io: &'a c_void,
 }
 
+//values of ImGuiDataType
+pub const IMGUI_DATA_TYPE_S32: i32 = 4;
+pub const IMGUI_DATA_TYPE_FLOAT: i32 = 8;
+pub const IMGUI_DATA_TYPE_DOUBLE: i32 = 9;
+
+//slider options, translated to ImGuiSliderFlags in gui_lib.cpp
+pub const SLIDER_ALWAYS_CLAMP: i32 = 1 << 0;
+pub const SLIDER_LOGARITHMIC: i32 = 1 << 1;
+pub const SLIDER_NO_INPUT: i32 = 1 << 2;
+
+//Selectable options, translated to ImGuiSelectableFlags in gui_lib.cpp
+pub const SELECTABLE_SPAN_ALL_COLUMNS: i32 = 1 << 0;
+pub const SELECTABLE_ALLOW_DOUBLE_CLICK: i32 = 1 << 1;
+
+//events returned by ImGui_Selectable
+pub const SELECTABLE_CLICKED: i32 = 1 << 0;
+pub const SELECTABLE_DOUBLE_CLICKED: i32 = 1 << 1;
+
+//returned by ImGui_GetKeyMods
+pub const KEY_MOD_CTRL: i32 = 1 << 0;
+pub const KEY_MOD_SHIFT: i32 = 1 << 1;
+pub const KEY_MOD_ALT: i32 = 1 << 2;
+pub const KEY_MOD_SUPER: i32 = 1 << 3;
+
+//Window options, translated to ImGuiWindowFlags in gui_lib.cpp
+pub const WINDOW_MENU_BAR: i32 = 1 << 0;
+
+//Plot options, translated to ImPlotFlags in implot_lib.cpp
+#[cfg(feature = "implot")]
+pub const PLOT_NO_LEGEND: i32 = 1 << 0;
+#[cfg(feature = "implot")]
+pub const PLOT_EQUAL: i32 = 1 << 1;
+
+//table options, translated to ImGuiTableFlags in gui_lib.cpp
+pub const TABLE_RESIZABLE: i32 = 1 << 0;
+pub const TABLE_REORDERABLE: i32 = 1 << 1;
+pub const TABLE_HIDEABLE: i32 = 1 << 2;
+pub const TABLE_SORTABLE: i32 = 1 << 3;
+pub const TABLE_SORT_MULTI: i32 = 1 << 4;
+pub const TABLE_ROW_BG: i32 = 1 << 5;
+pub const TABLE_BORDERS: i32 = 1 << 6;
+pub const TABLE_SCROLL_Y: i32 = 1 << 7;
+
+//table column options, translated to ImGuiTableColumnFlags in gui_lib.cpp
+pub const COLUMN_WIDTH_FIXED: i32 = 1 << 0;
+pub const COLUMN_WIDTH_STRETCH: i32 = 1 << 1;
+pub const COLUMN_NO_RESIZE: i32 = 1 << 2;
+pub const COLUMN_NO_REORDER: i32 = 1 << 3;
+pub const COLUMN_NO_HIDE: i32 = 1 << 4;
+pub const COLUMN_DEFAULT_HIDE: i32 = 1 << 5;
+pub const COLUMN_NO_SORT: i32 = 1 << 6;
+pub const COLUMN_DEFAULT_SORT: i32 = 1 << 7;
+
+//TabBar options, translated to ImGuiTabBarFlags in gui_lib.cpp
+pub const TAB_BAR_REORDERABLE: i32 = 1 << 0;
+pub const TAB_BAR_AUTO_SELECT_NEW_TABS: i32 = 1 << 1;
+
+//TabItem options, translated to ImGuiTabItemFlags in gui_lib.cpp
+pub const TAB_ITEM_SET_SELECTED: i32 = 1 << 0;
+pub const TAB_ITEM_UNSAVED: i32 = 1 << 1;
+
+//color options, translated to ImGuiColorEditFlags in gui_lib.cpp
+pub const COLOR_ALPHA: i32 = 1 << 0;
+pub const COLOR_PICKER: i32 = 1 << 1;
+pub const COLOR_HDR: i32 = 1 << 2;
+pub const COLOR_NO_INPUTS: i32 = 1 << 3;
+pub const COLOR_DISPLAY_HEX: i32 = 1 << 4;
+
+//InputText options, translated to ImGuiInputTextFlags in gui_lib.cpp
+pub const INPUT_TEXT_CHARS_DECIMAL: i32 = 1 << 0;
+pub const INPUT_TEXT_CHARS_HEXADECIMAL: i32 = 1 << 1;
+pub const INPUT_TEXT_CHARS_UPPERCASE: i32 = 1 << 2;
+pub const INPUT_TEXT_CHARS_NO_BLANK: i32 = 1 << 3;
+pub const INPUT_TEXT_AUTO_SELECT_ALL: i32 = 1 << 4;
+pub const INPUT_TEXT_ENTER_RETURNS_TRUE: i32 = 1 << 5;
+pub const INPUT_TEXT_READ_ONLY: i32 = 1 << 6;
+pub const INPUT_TEXT_PASSWORD: i32 = 1 << 7;
+pub const INPUT_TEXT_CALLBACK_HISTORY: i32 = 1 << 8;
+pub const INPUT_TEXT_CALLBACK_COMPLETION: i32 = 1 << 9;
+
+//events returned by ImGui_InputText
+pub const INPUT_TEXT_EDITED: i32 = 1 << 0;
+pub const INPUT_TEXT_SUBMITTED: i32 = 1 << 1;
+
+//events passed to InputTextHandler::edit
+pub const INPUT_TEXT_HISTORY_PREVIOUS: i32 = 1;
+pub const INPUT_TEXT_HISTORY_NEXT: i32 = 2;
+pub const INPUT_TEXT_COMPLETION: i32 = 3;
+
+//lets Dear ImGui grow the buffer of an InputText through ImGuiInputTextFlags_CallbackResize
+//and asks Rust for edits on history and completion events
+#[repr(C)]
+pub struct InputTextHandler {
+    pub data: *mut c_void,
+    pub resize: extern "C" fn(data: *mut c_void, size: usize) -> *mut u8,
+    pub edit: extern "C" fn(
+        data: *mut c_void,
+        event: i32,
+        text: *const u8,
+        len: usize,
+        cursor: usize,
+        edit: *mut InputTextEdit,
+    ) -> bool,
+}
+
+//replaces the bytes start..end of the edited text
+#[repr(C)]
+pub struct InputTextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub text: *const u8,
+    pub len: usize,
+}
+
 pub trait ImGuiGlue {
     fn render(&self);
 }
+
+#[cfg(feature = "implot")]
+extern "C" {
+    pub fn ImPlot_BeginPlot(title: *const u8, size: ImGui_Vec2, flags: i32) -> bool;
+    pub fn ImPlot_EndPlot();
+    pub fn ImPlot_SetupAxis(
+        axis: i32,
+        label: *const u8,
+        format: *const u8,
+        time: bool,
+        has_limits: bool,
+        min: f64,
+        max: f64,
+    );
+    pub fn ImPlot_PlotLine(label: *const u8, points: *const f64, count: usize);
+    pub fn ImPlot_PlotScatter(label: *const u8, points: *const f64, count: usize);
+    pub fn ImPlot_PlotBars(label: *const u8, points: *const f64, count: usize, width: f64);
+    pub fn ImPlot_PlotHeatmap(
+        label: *const u8,
+        values: *const f64,
+        rows: usize,
+        columns: usize,
+        scale_min: f64,
+        scale_max: f64,
+    );
+}
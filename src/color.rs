@@ -0,0 +1,199 @@
+use crate::backend::ImGui_Vec4;
+
+/// A RGBA color with components in the range 0.0 to 1.0.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0.0, 0.0, 0.0);
+    pub const WHITE: Color = Color::rgb(1.0, 1.0, 1.0);
+
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Color { r, g, b, a: 1.0 }
+    }
+
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color { r, g, b, a }
+    }
+
+    pub fn from_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color {
+            r: r as f32 / 255.0,
+            g: g as f32 / 255.0,
+            b: b as f32 / 255.0,
+            a: a as f32 / 255.0,
+        }
+    }
+
+    /// The components clamped to 0.0 to 1.0 and scaled to 0 to 255.
+    pub fn to_u8(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// Parses `#RRGGBB` or `#RRGGBBAA`, the `#` is optional.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim().trim_start_matches('#');
+        //from_str_radix would accept a sign in front of the digits
+        if !(hex.len() == 6 || hex.len() == 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        let alpha = if hex.len() == 8 { component(6)? } else { 255 };
+        Some(Color::from_u8(
+            component(0)?,
+            component(2)?,
+            component(4)?,
+            alpha,
+        ))
+    }
+
+    /// Formats the color as `#RRGGBBAA`.
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = self.to_u8();
+        format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+    }
+
+    /// Hue, saturation and value are in the range 0.0 to 1.0.
+    pub fn from_hsv(h: f32, s: f32, v: f32, a: f32) -> Self {
+        if s <= 0.0 {
+            return Color::rgba(v, v, v, a);
+        }
+        let h = (h.rem_euclid(1.0)) * 6.0;
+        let sector = h.floor();
+        let f = h - sector;
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - s * f);
+        let t = v * (1.0 - s * (1.0 - f));
+        let (r, g, b) = match sector as i32 {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        };
+        Color::rgba(r, g, b, a)
+    }
+
+    /// Returns hue, saturation and value in the range 0.0 to 1.0.
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+        if delta <= 0.0 {
+            return (0.0, 0.0, max);
+        }
+        let h = if max == self.r {
+            ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            (self.b - self.r) / delta + 2.0
+        } else {
+            (self.r - self.g) / delta + 4.0
+        };
+        (h / 6.0, delta / max, max)
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from(value: [f32; 4]) -> Self {
+        Color::rgba(value[0], value[1], value[2], value[3])
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
+impl From<ImGui_Vec4> for Color {
+    fn from(value: ImGui_Vec4) -> Self {
+        Color::rgba(value.x, value.y, value.z, value.w)
+    }
+}
+
+impl From<Color> for ImGui_Vec4 {
+    fn from(color: Color) -> Self {
+        ImGui_Vec4 {
+            x: color.r,
+            y: color.g,
+            z: color.b,
+            w: color.a,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Color, b: Color) {
+        let a: [f32; 4] = a.into();
+        let b: [f32; 4] = b.into();
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-5, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn hex() {
+        let color = Color::from_u8(0x12, 0xAB, 0xFF, 0x80);
+        assert_eq!(color.to_hex(), "#12ABFF80");
+        assert_eq!(Color::from_hex("#12ABFF80"), Some(color));
+        assert_eq!(Color::from_hex("12abff80"), Some(color));
+        assert_eq!(
+            Color::from_hex(" #12ABFF "),
+            Some(Color::from_u8(0x12, 0xAB, 0xFF, 255))
+        );
+    }
+
+    #[test]
+    fn invalid_hex() {
+        for hex in [
+            "",
+            "#12AB",
+            "#12ABFF8",
+            "#12ABFG",
+            "+12ABF",
+            "#+1+2+3",
+            "#12AB\u{e9}",
+        ] {
+            assert_eq!(Color::from_hex(hex), None, "{}", hex);
+        }
+    }
+
+    #[test]
+    fn u8_round_trip() {
+        for value in [0, 1, 127, 128, 254, 255] {
+            let color = Color::from_u8(value, 255 - value, value / 2, 255);
+            assert_eq!(color.to_u8(), [value, 255 - value, value / 2, 255]);
+        }
+        assert_eq!(Color::rgba(-1.0, 2.0, 0.5, 1.0).to_u8(), [0, 255, 128, 255]);
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        let colors = [
+            Color::BLACK,
+            Color::WHITE,
+            Color::rgb(1.0, 0.0, 0.0),
+            Color::rgb(0.0, 1.0, 0.0),
+            Color::rgb(0.0, 0.0, 1.0),
+            Color::rgb(1.0, 0.0, 0.5),
+            Color::rgba(0.2, 0.4, 0.6, 0.5),
+        ];
+        for color in colors {
+            let (h, s, v) = color.to_hsv();
+            assert_close(Color::from_hsv(h, s, v, color.a), color);
+        }
+        assert_close(
+            Color::from_hsv(1.0 / 3.0, 1.0, 1.0, 1.0),
+            Color::rgb(0.0, 1.0, 0.0),
+        );
+    }
+}
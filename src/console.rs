@@ -0,0 +1,234 @@
+use crate::backend::*;
+use crate::{Get, Gui, History, InputText, Set, Update};
+use std::{any::Any, collections::BTreeMap, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+type Command = Arc<dyn Fn(&Gui, &[&str]) -> String + Send + Sync>;
+
+const BUILTIN_COMMANDS: [&str; 3] = ["clear", "help", "history"];
+const DEFAULT_MAX_LINES: usize = 10_000;
+
+/// A scrolling log with an input line. Submitted lines are split at whitespace and dispatched to the
+/// registered command of the first word, its output is appended to the log.
+///
+/// The input supports history navigation with the arrow keys and tab completion of command names.
+/// `clear`, `help` and `history` are always available.
+#[derive(Clone)]
+pub struct Console {
+    label: Arc<RwLock<String>>,
+    log: Arc<RwLock<Vec<String>>>,
+    //the oldest lines are dropped when the log grows longer
+    max_lines: Arc<RwLock<usize>>,
+    input: InputText,
+    commands: Arc<RwLock<BTreeMap<String, Command>>>,
+    history: Arc<RwLock<Vec<String>>>,
+    history_position: Arc<RwLock<Option<usize>>>,
+    submitted: Arc<RwLock<Option<String>>>,
+    scroll_to_bottom: Arc<RwLock<bool>>,
+}
+
+impl Console {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        let commands: Arc<RwLock<BTreeMap<String, Command>>> =
+            Arc::new(RwLock::new(BTreeMap::new()));
+        let history: Arc<RwLock<Vec<String>>> = Arc::new(RwLock::new(vec![]));
+        let history_position: Arc<RwLock<Option<usize>>> = Arc::new(RwLock::new(None));
+
+        let completion_commands = commands.clone();
+        let navigation_history = history.clone();
+        let navigation_position = history_position.clone();
+        let input = InputText::new(&format!("##input{}", label))
+            .enter_returns_true()
+            .set_completion_callback(move |word: &str| {
+                let commands = completion_commands.blocking_read();
+                let mut candidates: Vec<String> = BUILTIN_COMMANDS
+                    .iter()
+                    .copied()
+                    .chain(commands.keys().map(String::as_str))
+                    .filter(|name| name.starts_with(word))
+                    .map(String::from)
+                    .collect();
+                candidates.sort();
+                candidates.dedup();
+                candidates
+            })
+            .set_history_callback(move |direction: History| {
+                let history = navigation_history.blocking_read();
+                let mut position = navigation_position.blocking_write();
+                let new_position = match (direction, *position) {
+                    (History::Previous, None) => history.len().checked_sub(1),
+                    (History::Previous, Some(i)) => Some(i.saturating_sub(1)),
+                    (History::Next, None) => return None,
+                    (History::Next, Some(i)) => Some(i + 1).filter(|i| *i < history.len()),
+                };
+                *position = new_position;
+                Some(new_position.map_or(String::new(), |i| history[i].clone()))
+            });
+
+        Console {
+            label: Arc::new(RwLock::new(label)),
+            log: Arc::new(RwLock::new(vec![])),
+            max_lines: Arc::new(RwLock::new(DEFAULT_MAX_LINES)),
+            input,
+            commands,
+            history,
+            history_position,
+            submitted: Arc::new(RwLock::new(None)),
+            scroll_to_bottom: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    /// Registers a command. The handler receives the arguments following the command name,
+    /// a non empty return value is appended to the log.
+    pub fn command<T>(self, name: &str, handler: T) -> Self
+    where
+        T: 'static + Send + Sync + Fn(&Gui, &[&str]) -> String,
+    {
+        self.commands
+            .blocking_write()
+            .insert(String::from(name), Arc::new(handler));
+        self
+    }
+
+    /// The number of lines the log keeps, 10000 by default.
+    pub fn max_lines(self, max_lines: usize) -> Self {
+        *self.max_lines.blocking_write() = max_lines;
+        self
+    }
+
+    /// Appends lines to the log, dropping the oldest lines beyond `max_lines`. Can be called from
+    /// any thread.
+    pub fn print(&self, text: &str) {
+        let mut log = self.log.blocking_write();
+        log.extend(text.lines().map(String::from));
+        let excess = log.len().saturating_sub(*self.max_lines.blocking_read());
+        log.drain(..excess);
+    }
+
+    pub fn clear(&self) {
+        self.log.blocking_write().clear();
+    }
+
+    /// Runs a line as if it was submitted through the input.
+    pub fn execute(&self, gui: &Gui, line: &str) {
+        let line = line.trim();
+        if line.is_empty() {
+            return;
+        }
+        self.print(&format!("# {}", line));
+        *self.scroll_to_bottom.blocking_write() = true;
+
+        {
+            let mut history = self.history.blocking_write();
+            history.retain(|entry| entry != line);
+            history.push(String::from(line));
+        }
+        *self.history_position.blocking_write() = None;
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words[0] {
+            "clear" => self.clear(),
+            "help" => {
+                let commands = self.commands.blocking_read();
+                let names: Vec<&str> = BUILTIN_COMMANDS
+                    .iter()
+                    .copied()
+                    .chain(commands.keys().map(String::as_str))
+                    .collect();
+                let help = format!("commands: {}", names.join(", "));
+                drop(commands);
+                self.print(&help);
+            }
+            "history" => {
+                let history = self.history.blocking_read().join("\n");
+                self.print(&history);
+            }
+            name => {
+                let command = self.commands.blocking_read().get(name).cloned();
+                match command {
+                    Some(command) => {
+                        let output = command(gui, &words[1..]);
+                        if !output.is_empty() {
+                            self.print(&output);
+                        }
+                    }
+                    None => self.print(&format!("unknown command: '{}'", name)),
+                }
+            }
+        }
+    }
+}
+
+impl Get<Vec<String>> for Console {
+    fn get(&self) -> Vec<String> {
+        self.log.blocking_read().clone()
+    }
+}
+
+impl Update for Console {
+    fn update(&self, gui: &Gui) -> bool {
+        let footer_height = unsafe { ImGui_GetFrameHeightWithSpacing() };
+        if unsafe {
+            ImGui_BeginChild(
+                self.label.blocking_read().as_ptr(),
+                0.0,
+                -footer_height,
+                false,
+            )
+        } {
+            //only the visible lines are drawn
+            let log = self.log.blocking_read();
+            unsafe {
+                let clipper = ImGui_ListClipperBegin(log.len());
+                let (mut start, mut end) = (0, 0);
+                while ImGui_ListClipperStep(clipper, &mut start, &mut end) {
+                    for line in &log[start..end] {
+                        ImGui_Text(line.as_ptr(), line.len());
+                    }
+                }
+                ImGui_ListClipperEnd(clipper);
+            }
+            //stay at the bottom unless the user scrolled up, but always show the output of a new command
+            let scroll_to_bottom = std::mem::take(&mut *self.scroll_to_bottom.blocking_write());
+            unsafe {
+                if scroll_to_bottom || ImGui_GetScrollY() >= ImGui_GetScrollMaxY() {
+                    ImGui_SetScrollHereY(1.0);
+                }
+            }
+        }
+        unsafe { ImGui_EndChild() }
+
+        if self.input.update(gui) {
+            self.input.call_callback(gui);
+        }
+
+        let completions: Vec<String> = self.input.get();
+        if !completions.is_empty() {
+            self.print(&completions.join("  "));
+        }
+
+        let submitted: bool = self.input.get();
+        if submitted {
+            *self.submitted.blocking_write() = Some(self.input.get());
+            self.input.set(String::new());
+            unsafe { ImGui_SetKeyboardFocusHere(-1) }
+        }
+        submitted
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        let line = self.submitted.blocking_write().take();
+        if let Some(line) = line {
+            self.execute(gui, &line);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
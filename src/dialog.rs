@@ -0,0 +1,164 @@
+use crate::{Container2, Get, Gui, InputText, PopupModal, Text};
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread::{self, ThreadId},
+};
+use tokio::sync::oneshot;
+
+const OK: &str = "OK";
+const CANCEL: &str = "Cancel";
+const YES: &str = "Yes";
+const NO: &str = "No";
+
+//makes the titles unique, so the same dialog can be requested by several threads
+static DIALOGS: AtomicUsize = AtomicUsize::new(0);
+
+/// Builds a queued dialog on the GUI thread, so dialogs can be requested from async code which
+/// must not block on the locks of the widgets.
+pub(crate) type DialogBuilder = Box<dyn FnOnce() -> PopupModal + Send>;
+
+/// The dialogs waiting to be shown.
+#[derive(Default)]
+pub(crate) struct DialogQueue {
+    builders: VecDeque<DialogBuilder>,
+    //set by Gui::start, None before
+    gui_thread: Option<ThreadId>,
+    //set once the GUI thread stopped, no dialog will be shown anymore
+    closed: bool,
+}
+
+impl DialogQueue {
+    pub(crate) fn start(&mut self, gui_thread: ThreadId) {
+        self.gui_thread = Some(gui_thread);
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<DialogBuilder> {
+        self.builders.pop_front()
+    }
+
+    /// Drops the queued dialogs and their senders, so threads waiting for an answer return.
+    pub(crate) fn close(&mut self) {
+        self.closed = true;
+        self.builders.clear();
+    }
+}
+
+impl Gui {
+    /// Shows the modal once the dialogs opened before it are closed and removes it when it gets
+    /// closed. Can be called from any thread.
+    pub fn open_modal(&self, modal: PopupModal) {
+        self.queue_dialog(Box::new(move || modal));
+    }
+
+    /// Shows a message with an OK button and waits until the user closes it.
+    ///
+    /// Returns right away if the GUI is not running or when awaited on the GUI thread, e.g. in a
+    /// callback, because nothing would show the dialog.
+    pub async fn message_box(&self, title: &str, text: &str) {
+        let _ = self.dialog(title, text, &[OK]).await;
+    }
+
+    /// Like `message_box`, but blocks the calling thread. Must not be called from async code.
+    /// Returns right away if the GUI is not running or on the GUI thread.
+    pub fn blocking_message_box(&self, title: &str, text: &str) {
+        let _ = self.dialog(title, text, &[OK]).blocking_recv();
+    }
+
+    /// Asks a question with yes and no buttons and blocks until the user answers, true for yes.
+    ///
+    /// Must not be called from async code. Returns false right away if the GUI is not running or
+    /// on the GUI thread.
+    pub fn confirm(&self, title: &str, text: &str) -> bool {
+        let answer = self.dialog(title, text, &[YES, NO]).blocking_recv();
+        answer.ok().flatten().as_deref() == Some(YES)
+    }
+
+    /// Asks for a line of text and blocks until the user answers, None if the user cancels.
+    ///
+    /// Must not be called from async code. Returns None right away if the GUI is not running or
+    /// on the GUI thread.
+    pub fn prompt(&self, label: &str) -> Option<String> {
+        let input = InputText::new("##prompt");
+        let (sender, answer) = oneshot::channel();
+        let builder_input = input.clone();
+        let title = unique_title(label);
+        self.queue_answered_dialog(Box::new(move || {
+            let modal = PopupModal::new(&title);
+            let submit = modal.clone();
+            //enter answers like the OK button
+            let input = builder_input
+                .enter_returns_true()
+                .set_submit_callback(move |_gui: &Gui| submit.close(Some(OK)));
+            answer_with(modal.add(input).button(OK).button(CANCEL), sender)
+        }));
+
+        match answer.blocking_recv() {
+            Ok(Some(button)) if button == OK => Some(input.get()),
+            _ => None,
+        }
+    }
+
+    /// Opens a dialog with the text and buttons, the receiver gets the label of the clicked
+    /// button. The sender gets dropped if the GUI is not running or closed before the user answers.
+    fn dialog(
+        &self,
+        title: &str,
+        text: &str,
+        buttons: &'static [&'static str],
+    ) -> oneshot::Receiver<Option<String>> {
+        let (sender, answer) = oneshot::channel();
+        let title = unique_title(title);
+        let text = String::from(text);
+        self.queue_answered_dialog(Box::new(move || {
+            let modal = buttons.iter().fold(
+                PopupModal::new(&title).add(Text::new(&text)),
+                |modal, button| modal.button(button),
+            );
+            answer_with(modal, sender)
+        }));
+        answer
+    }
+
+    //after the GUI closed the builder is dropped right away, so the dialog answers None
+    fn queue_dialog(&self, builder: DialogBuilder) {
+        let mut queue = self.dialog_queue.lock().unwrap();
+        if !queue.closed {
+            queue.builders.push_back(builder);
+        }
+    }
+
+    //like queue_dialog for dialogs someone waits for. Before the start no thread shows the dialog
+    //and waiting on the GUI thread would stop the frames, so the builder is dropped and the
+    //dialog answers None right away
+    fn queue_answered_dialog(&self, builder: DialogBuilder) {
+        let mut queue = self.dialog_queue.lock().unwrap();
+        let shown = queue
+            .gui_thread
+            .is_some_and(|gui_thread| gui_thread != thread::current().id());
+        if shown && !queue.closed {
+            queue.builders.push_back(builder);
+        }
+    }
+}
+
+fn unique_title(title: &str) -> String {
+    format!(
+        "{}##dialog{}",
+        title,
+        DIALOGS.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Sends the result of the modal once it gets closed.
+fn answer_with(modal: PopupModal, sender: oneshot::Sender<Option<String>>) -> PopupModal {
+    let sender = Mutex::new(Some(sender));
+    modal.set_result_callback(move |_gui: &Gui, result| {
+        if let Some(sender) = sender.lock().unwrap().take() {
+            let _ = sender.send(result.map(String::from));
+        }
+    })
+}
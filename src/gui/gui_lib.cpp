@@ -4,9 +4,14 @@
 // Read online: https://github.com/ocornut/imgui/tree/master/docs
 
 #include <iostream>
+#include <string>
+#include <cctype>
 #include "imgui.h"
 #include "imgui_impl_glfw.h"
 #include "imgui_impl_opengl3.h"
+#ifdef RUST_GUI_IMPLOT
+#include "implot.h"
+#endif
 #include <stdio.h>
 #if defined(IMGUI_IMPL_OPENGL_ES2)
 #include <GLES2/gl2.h>
@@ -25,6 +30,15 @@ static void glfw_error_callback(int error, const char* description)
     fprintf(stderr, "Glfw Error %d: %s\n", error, description);
 }
 
+//fonts used for markdown headings, loaded in init_gui()
+static ImFont* heading_fonts[3] = { NULL, NULL, NULL };
+
+//acts as replacement of the ImVec2 struct
+extern "C" struct ImGui_Vec2 {
+    float x;
+    float y;
+};
+
 //acts as replacement of the ImVec4 struct
 extern "C" struct ImGui_Vec4 {
     float x;
@@ -80,6 +94,9 @@ extern "C" GUI init_gui(const char* window_label)
     // Setup Dear ImGui context
     IMGUI_CHECKVERSION();
     ImGui::CreateContext();
+#ifdef RUST_GUI_IMPLOT
+    ImPlot::CreateContext();
+#endif
     ImGuiIO& io = ImGui::GetIO(); (void)io;
     io.ConfigFlags |= ImGuiConfigFlags_NavEnableKeyboard;       // Enable Keyboard Controls
     //io.ConfigFlags |= ImGuiConfigFlags_NavEnableGamepad;      // Enable Gamepad Controls
@@ -118,6 +135,13 @@ extern "C" GUI init_gui(const char* window_label)
     //io.Fonts->AddFontFromFileTTF("../../misc/fonts/ProggyTiny.ttf", 10.0f);
     //ImFont* font = io.Fonts->AddFontFromFileTTF("c:\\Windows\\Fonts\\ArialUni.ttf", 18.0f, NULL, io.Fonts->GetGlyphRangesJapanese());
     //IM_ASSERT(font != NULL);
+    io.Fonts->AddFontDefault();
+    const float heading_sizes[3] = { 26.0f, 20.0f, 16.0f };
+    for (int i = 0; i < 3; i++) {
+        ImFontConfig config;
+        config.SizePixels = heading_sizes[i];
+        heading_fonts[i] = io.Fonts->AddFontDefault(&config);
+    }
     GUI gui;
     gui.window = window;
     gui.io = static_cast<ImGuiIO*>(&io);
@@ -140,6 +164,10 @@ extern "C" void start_frame() {
     ImGui_ImplOpenGL3_NewFrame();
     ImGui_ImplGlfw_NewFrame();
     ImGui::NewFrame();
+}
+
+// called after the main menu bar, so the docked windows are placed below it
+extern "C" void ImGui_DockSpaceOverViewport() {
     ImGui::DockSpaceOverViewport();
 }
 
@@ -171,6 +199,9 @@ extern "C" void destroy_gui(void* window) {
     // Cleanup
     ImGui_ImplOpenGL3_Shutdown();
     ImGui_ImplGlfw_Shutdown();
+#ifdef RUST_GUI_IMPLOT
+    ImPlot::DestroyContext();
+#endif
     ImGui::DestroyContext();
 
     glfwDestroyWindow((GLFWwindow*)window);
@@ -181,8 +212,41 @@ extern "C" void ImGui_Checkbox(const char* label, bool* value) {
     ImGui::Checkbox(label, value);
 }
 
-extern "C" void ImGui_Text(const char* text) {
-    ImGui::Text(text);
+// the text is rendered unformatted so user strings containing '%' are displayed as is
+extern "C" void ImGui_Text(const char* text, size_t len) {
+    ImGui::TextUnformatted(text, text + len);
+}
+
+extern "C" void ImGui_TextColored(const char* text, size_t len, ImGui_Vec4 color) {
+    ImGui::PushStyleColor(ImGuiCol_Text, ImVec4(color.x, color.y, color.z, color.w));
+    ImGui::TextUnformatted(text, text + len);
+    ImGui::PopStyleColor();
+}
+
+extern "C" void ImGui_TextWrapped(const char* text, size_t len, float wrap_width) {
+    ImGui::PushTextWrapPos(wrap_width > 0.0f ? ImGui::GetCursorPosX() + wrap_width : 0.0f);
+    ImGui::TextUnformatted(text, text + len);
+    ImGui::PopTextWrapPos();
+}
+
+extern "C" void ImGui_TextDisabled(const char* text, size_t len) {
+    ImGui::PushStyleColor(ImGuiCol_Text, ImGui::GetStyle().Colors[ImGuiCol_TextDisabled]);
+    ImGui::TextUnformatted(text, text + len);
+    ImGui::PopStyleColor();
+}
+
+extern "C" void ImGui_BulletText(const char* text, size_t len) {
+    ImGui::Bullet();
+    ImGui::TextUnformatted(text, text + len);
+}
+
+extern "C" void ImGui_SeparatorText(const char* text, size_t len) {
+    std::string label(text, len);
+    ImGui::SeparatorText(label.c_str());
+}
+
+extern "C" void ImGui_LabelText(const char* label, const char* text, size_t len) {
+    ImGui::LabelText(label, "%.*s", (int)len, text);
 }
 
 extern "C" void ImGui_Button(const char* text, bool* value) {
@@ -194,7 +258,14 @@ extern "C" void ImGui_Button(const char* text, bool* value) {
     // printf("after: %d\n", *value);
 }
 
-extern "C" void ImGui_Begin(const char* name, bool* close, int flags) {
+//has to match the Window options in src/backend.rs
+enum WindowOption {
+    WindowOption_MenuBar = 1 << 0,
+};
+
+extern "C" void ImGui_Begin(const char* name, bool* close, int options) {
+    ImGuiWindowFlags flags = 0;
+    if (options & WindowOption_MenuBar) flags |= ImGuiWindowFlags_MenuBar;
     ImGui::Begin(name, close, flags);
 }
 
@@ -202,28 +273,735 @@ extern "C" void ImGui_End() {
     ImGui::End();
 }
 
-extern "C" void ImGui_ColorEdit3(const char* name, ImVec4* color) {
-    ImGui::ColorEdit3(name, (float*)color);
+extern "C" bool ImGui_BeginCombo(const char* label, const char* preview, size_t preview_len) {
+    std::string preview_value(preview, preview_len);
+    return ImGui::BeginCombo(label, preview_value.c_str(), ImGuiComboFlags_HeightLarge);
+}
+
+extern "C" void ImGui_EndCombo() {
+    ImGui::EndCombo();
+}
+
+extern "C" bool ImGui_BeginListBox(const char* label, ImGui_Vec2 size) {
+    return ImGui::BeginListBox(label, ImVec2(size.x, size.y));
+}
+
+extern "C" void ImGui_EndListBox() {
+    ImGui::EndListBox();
+}
+
+//has to match the Selectable options and events in src/backend.rs
+enum SelectableOption {
+    SelectableOption_SpanAllColumns = 1 << 0,
+    SelectableOption_AllowDoubleClick = 1 << 1,
+};
+
+enum SelectableEvent {
+    SelectableEvent_Clicked = 1 << 0,
+    SelectableEvent_DoubleClicked = 1 << 1,
+};
+
+// the item text is not null terminated, the id has to be pushed by the caller
+extern "C" int ImGui_Selectable(const char* text, size_t len, bool selected, int options, ImGui_Vec2 size) {
+    ImGuiSelectableFlags flags = 0;
+    if (options & SelectableOption_SpanAllColumns) flags |= ImGuiSelectableFlags_SpanAllColumns;
+    if (options & SelectableOption_AllowDoubleClick) flags |= ImGuiSelectableFlags_AllowDoubleClick;
+
+    std::string label(text, len);
+    int events = 0;
+    if (ImGui::Selectable(label.c_str(), selected, flags, ImVec2(size.x, size.y))) {
+        events |= SelectableEvent_Clicked;
+        if ((options & SelectableOption_AllowDoubleClick) && ImGui::IsMouseDoubleClicked(ImGuiMouseButton_Left))
+            events |= SelectableEvent_DoubleClicked;
+    }
+    return events;
+}
+
+//has to match the key modifiers in src/backend.rs
+enum KeyMod {
+    KeyMod_Ctrl = 1 << 0,
+    KeyMod_Shift = 1 << 1,
+    KeyMod_Alt = 1 << 2,
+    KeyMod_Super = 1 << 3,
+};
+
+extern "C" int ImGui_GetKeyMods() {
+    ImGuiIO& io = ImGui::GetIO();
+    return (io.KeyCtrl ? KeyMod_Ctrl : 0) | (io.KeyShift ? KeyMod_Shift : 0) | (io.KeyAlt ? KeyMod_Alt : 0) | (io.KeySuper ? KeyMod_Super : 0);
+}
+
+extern "C" void ImGui_SetItemDefaultFocus() {
+    ImGui::SetItemDefaultFocus();
+}
+
+// full width text input which gets the keyboard focus when its window appears
+extern "C" bool ImGui_InputFilter(const char* id, char* buffer, size_t buffer_size) {
+    if (ImGui::IsWindowAppearing())
+        ImGui::SetKeyboardFocusHere();
+    ImGui::SetNextItemWidth(-FLT_MIN);
+    return ImGui::InputTextWithHint(id, "filter", buffer, buffer_size);
+}
+
+// the label is not null terminated, the id has to be pushed by the caller
+extern "C" bool ImGui_RadioButton(const char* text, size_t len, bool active) {
+    std::string label(text, len);
+    return ImGui::RadioButton(label.c_str(), active);
+}
+
+extern "C" void ImGui_PushStrID(const char* id) {
+    ImGui::PushID(id);
+}
+
+extern "C" void ImGui_PushID(int id) {
+    ImGui::PushID(id);
+}
+
+extern "C" void ImGui_PopID() {
+    ImGui::PopID();
+}
+
+// overlay is null to show the percentage, a negative fraction makes Dear ImGui animate an indeterminate bar
+extern "C" void ImGui_ProgressBar(float fraction, ImGui_Vec2 size, const char* overlay, size_t overlay_len, bool indeterminate) {
+    if (indeterminate)
+        fraction = -1.0f * (float)ImGui::GetTime();
+    if (overlay == nullptr) {
+        ImGui::ProgressBar(fraction, ImVec2(size.x, size.y));
+        return;
+    }
+    std::string overlay_text(overlay, overlay_len);
+    ImGui::ProgressBar(fraction, ImVec2(size.x, size.y), overlay_text.c_str());
+}
+
+// the values are a ring buffer starting at offset, overlay is null to show no text
+extern "C" void ImGui_PlotSamples(const char* label, const float* values, size_t count, size_t offset, const char* overlay, size_t overlay_len,
+                                  float scale_min, float scale_max, ImGui_Vec2 size, bool histogram) {
+    std::string overlay_text;
+    if (overlay != nullptr)
+        overlay_text.assign(overlay, overlay_len);
+    const char* overlay_ptr = overlay != nullptr ? overlay_text.c_str() : nullptr;
+    if (histogram)
+        ImGui::PlotHistogram(label, values, (int)count, (int)offset, overlay_ptr, scale_min, scale_max, ImVec2(size.x, size.y));
+    else
+        ImGui::PlotLines(label, values, (int)count, (int)offset, overlay_ptr, scale_min, scale_max, ImVec2(size.x, size.y));
+}
+
+//has to match the table options in src/backend.rs
+enum TableOption {
+    TableOption_Resizable = 1 << 0,
+    TableOption_Reorderable = 1 << 1,
+    TableOption_Hideable = 1 << 2,
+    TableOption_Sortable = 1 << 3,
+    TableOption_SortMulti = 1 << 4,
+    TableOption_RowBg = 1 << 5,
+    TableOption_Borders = 1 << 6,
+    TableOption_ScrollY = 1 << 7,
+};
+
+//has to match the table column options in src/backend.rs
+enum ColumnOption {
+    ColumnOption_WidthFixed = 1 << 0,
+    ColumnOption_WidthStretch = 1 << 1,
+    ColumnOption_NoResize = 1 << 2,
+    ColumnOption_NoReorder = 1 << 3,
+    ColumnOption_NoHide = 1 << 4,
+    ColumnOption_DefaultHide = 1 << 5,
+    ColumnOption_NoSort = 1 << 6,
+    ColumnOption_DefaultSort = 1 << 7,
+};
+
+//has to match SortSpec in src/table.rs
+struct SortSpec {
+    size_t column;
+    bool ascending;
+};
+
+extern "C" bool ImGui_BeginTable(const char* id, int columns, int options, ImGui_Vec2 size) {
+    ImGuiTableFlags flags = 0;
+    if (options & TableOption_Resizable) flags |= ImGuiTableFlags_Resizable;
+    if (options & TableOption_Reorderable) flags |= ImGuiTableFlags_Reorderable;
+    if (options & TableOption_Hideable) flags |= ImGuiTableFlags_Hideable;
+    if (options & TableOption_Sortable) flags |= ImGuiTableFlags_Sortable;
+    if (options & TableOption_SortMulti) flags |= ImGuiTableFlags_SortMulti;
+    if (options & TableOption_RowBg) flags |= ImGuiTableFlags_RowBg;
+    if (options & TableOption_Borders) flags |= ImGuiTableFlags_Borders;
+    if (options & TableOption_ScrollY) flags |= ImGuiTableFlags_ScrollY;
+    return ImGui::BeginTable(id, columns, flags, ImVec2(size.x, size.y));
+}
+
+extern "C" void ImGui_EndTable() {
+    ImGui::EndTable();
+}
+
+extern "C" void ImGui_TableSetupColumn(const char* label, int options, float width) {
+    ImGuiTableColumnFlags flags = 0;
+    if (options & ColumnOption_WidthFixed) flags |= ImGuiTableColumnFlags_WidthFixed;
+    if (options & ColumnOption_WidthStretch) flags |= ImGuiTableColumnFlags_WidthStretch;
+    if (options & ColumnOption_NoResize) flags |= ImGuiTableColumnFlags_NoResize;
+    if (options & ColumnOption_NoReorder) flags |= ImGuiTableColumnFlags_NoReorder;
+    if (options & ColumnOption_NoHide) flags |= ImGuiTableColumnFlags_NoHide;
+    if (options & ColumnOption_DefaultHide) flags |= ImGuiTableColumnFlags_DefaultHide;
+    if (options & ColumnOption_NoSort) flags |= ImGuiTableColumnFlags_NoSort;
+    if (options & ColumnOption_DefaultSort) flags |= ImGuiTableColumnFlags_DefaultSort;
+    ImGui::TableSetupColumn(label, flags, width);
+}
+
+extern "C" void ImGui_TableSetupScrollFreeze(int columns, int rows) {
+    ImGui::TableSetupScrollFreeze(columns, rows);
+}
+
+extern "C" void ImGui_TableHeadersRow() {
+    ImGui::TableHeadersRow();
+}
+
+extern "C" void ImGui_TableNextRow() {
+    ImGui::TableNextRow();
+}
+
+extern "C" bool ImGui_TableSetColumnIndex(int column) {
+    return ImGui::TableSetColumnIndex(column);
+}
+
+// returns -1 if the sorting did not change since the last call, otherwise the number of specs written
+extern "C" int ImGui_TableSortSpecs(SortSpec* specs, size_t capacity) {
+    ImGuiTableSortSpecs* sort_specs = ImGui::TableGetSortSpecs();
+    if (sort_specs == nullptr || !sort_specs->SpecsDirty)
+        return -1;
+    int count = 0;
+    for (int i = 0; i < sort_specs->SpecsCount && (size_t)i < capacity; i++) {
+        specs[count].column = (size_t)sort_specs->Specs[i].ColumnIndex;
+        specs[count].ascending = sort_specs->Specs[i].SortDirection != ImGuiSortDirection_Descending;
+        count++;
+    }
+    sort_specs->SpecsDirty = false;
+    return count;
+}
+
+extern "C" ImGuiListClipper* ImGui_ListClipperBegin(size_t count) {
+    ImGuiListClipper* clipper = new ImGuiListClipper();
+    clipper->Begin((int)count);
+    return clipper;
+}
+
+// writes the range of rows to draw, returns false when all visible rows are drawn
+extern "C" bool ImGui_ListClipperStep(ImGuiListClipper* clipper, size_t* start, size_t* end) {
+    if (!clipper->Step())
+        return false;
+    *start = (size_t)clipper->DisplayStart;
+    *end = (size_t)clipper->DisplayEnd;
+    return true;
+}
+
+extern "C" void ImGui_ListClipperEnd(ImGuiListClipper* clipper) {
+    clipper->End();
+    delete clipper;
+}
+
+extern "C" bool ImGui_BeginMainMenuBar() {
+    return ImGui::BeginMainMenuBar();
+}
+
+extern "C" void ImGui_EndMainMenuBar() {
+    ImGui::EndMainMenuBar();
+}
+
+extern "C" bool ImGui_BeginMenuBar() {
+    return ImGui::BeginMenuBar();
+}
+
+extern "C" void ImGui_EndMenuBar() {
+    ImGui::EndMenuBar();
+}
+
+extern "C" bool ImGui_BeginMenu(const char* label, bool enabled) {
+    return ImGui::BeginMenu(label, enabled);
+}
+
+extern "C" void ImGui_EndMenu() {
+    ImGui::EndMenu();
+}
+
+// shortcut is null for items without a shortcut
+extern "C" bool ImGui_MenuItem(const char* label, const char* shortcut, bool checked, bool enabled) {
+    return ImGui::MenuItem(label, shortcut, checked, enabled);
+}
+
+// the key is compared case insensitive with the key names of Dear ImGui, e.g. "S", "F5" or "Delete"
+extern "C" bool ImGui_IsShortcutPressed(int mods, const char* key, size_t key_len, bool focused_window_only) {
+    if (mods != ImGui_GetKeyMods())
+        return false;
+    // keys without modifiers belong to the text field being edited
+    if (ImGui::GetIO().WantTextInput && !(mods & (KeyMod_Ctrl | KeyMod_Alt | KeyMod_Super)))
+        return false;
+    if (focused_window_only && !ImGui::IsWindowFocused(ImGuiFocusedFlags_RootAndChildWindows))
+        return false;
+    for (int k = ImGuiKey_NamedKey_BEGIN; k < ImGuiKey_NamedKey_END; k++) {
+        const char* name = ImGui::GetKeyName((ImGuiKey)k);
+        size_t i = 0;
+        while (i < key_len && name[i] != '\0' && tolower((unsigned char)name[i]) == tolower((unsigned char)key[i]))
+            i++;
+        if (i == key_len && name[i] == '\0')
+            return ImGui::IsKeyPressed((ImGuiKey)k, false);
+    }
+    return false;
+}
+
+// begins a tooltip if the previous item is hovered, long text is wrapped
+extern "C" bool ImGui_BeginItemTooltip() {
+    if (!ImGui::BeginItemTooltip())
+        return false;
+    ImGui::PushTextWrapPos(ImGui::GetFontSize() * 35.0f);
+    return true;
+}
+
+extern "C" void ImGui_EndTooltip() {
+    ImGui::PopTextWrapPos();
+    ImGui::EndTooltip();
+}
+
+extern "C" void ImGui_OpenPopup(const char* id) {
+    ImGui::OpenPopup(id);
+}
+
+extern "C" bool ImGui_BeginPopup(const char* id) {
+    return ImGui::BeginPopup(id);
+}
+
+// open is null for modals without a close button, they are centered when they appear
+extern "C" bool ImGui_BeginPopupModal(const char* title, bool* open) {
+    ImGui::SetNextWindowPos(ImGui::GetMainViewport()->GetCenter(), ImGuiCond_Appearing, ImVec2(0.5f, 0.5f));
+    return ImGui::BeginPopupModal(title, open, ImGuiWindowFlags_AlwaysAutoResize);
+}
+
+extern "C" bool ImGui_BeginPopupContextItem(const char* id) {
+    return ImGui::BeginPopupContextItem(id);
+}
+
+extern "C" bool ImGui_BeginPopupContextWindow(const char* id) {
+    return ImGui::BeginPopupContextWindow(id, ImGuiPopupFlags_MouseButtonRight | ImGuiPopupFlags_NoOpenOverItems);
+}
+
+extern "C" void ImGui_EndPopup() {
+    ImGui::EndPopup();
+}
+
+extern "C" void ImGui_CloseCurrentPopup() {
+    ImGui::CloseCurrentPopup();
+}
+
+//has to match the TabBar and TabItem options in src/backend.rs
+enum TabBarOption {
+    TabBarOption_Reorderable = 1 << 0,
+    TabBarOption_AutoSelectNewTabs = 1 << 1,
+};
+
+enum TabItemOption {
+    TabItemOption_SetSelected = 1 << 0,
+    TabItemOption_Unsaved = 1 << 1,
+};
+
+extern "C" bool ImGui_BeginTabBar(const char* id, int options) {
+    ImGuiTabBarFlags flags = 0;
+    if (options & TabBarOption_Reorderable) flags |= ImGuiTabBarFlags_Reorderable;
+    if (options & TabBarOption_AutoSelectNewTabs) flags |= ImGuiTabBarFlags_AutoSelectNewTabs;
+    return ImGui::BeginTabBar(id, flags);
+}
+
+extern "C" void ImGui_EndTabBar() {
+    ImGui::EndTabBar();
+}
+
+// open is null for tabs without a close button
+extern "C" bool ImGui_BeginTabItem(const char* label, bool* open, int options) {
+    ImGuiTabItemFlags flags = 0;
+    if (options & TabItemOption_SetSelected) flags |= ImGuiTabItemFlags_SetSelected;
+    if (options & TabItemOption_Unsaved) flags |= ImGuiTabItemFlags_UnsavedDocument;
+    return ImGui::BeginTabItem(label, open, flags);
+}
+
+extern "C" void ImGui_EndTabItem() {
+    ImGui::EndTabItem();
+}
+
+//has to match the color options in src/backend.rs
+enum ColorOption {
+    ColorOption_Alpha = 1 << 0,
+    ColorOption_Picker = 1 << 1,
+    ColorOption_HDR = 1 << 2,
+    ColorOption_NoInputs = 1 << 3,
+    ColorOption_DisplayHex = 1 << 4,
+};
+
+static ImGuiColorEditFlags color_flags(int options) {
+    ImGuiColorEditFlags flags = 0;
+    if (!(options & ColorOption_Alpha)) flags |= ImGuiColorEditFlags_NoAlpha;
+    if (options & ColorOption_HDR) flags |= ImGuiColorEditFlags_HDR | ImGuiColorEditFlags_Float;
+    if (options & ColorOption_NoInputs) flags |= ImGuiColorEditFlags_NoInputs;
+    if (options & ColorOption_DisplayHex) flags |= ImGuiColorEditFlags_DisplayHex;
+    return flags;
+}
+
+extern "C" bool ImGui_ColorEdit(const char* name, ImGui_Vec4* color, int options) {
+    float* components = &color->x;
+    if (options & ColorOption_Picker) {
+        if (options & ColorOption_Alpha)
+            return ImGui::ColorPicker4(name, components, color_flags(options));
+        return ImGui::ColorPicker3(name, components, color_flags(options));
+    }
+    if (options & ColorOption_Alpha)
+        return ImGui::ColorEdit4(name, components, color_flags(options));
+    return ImGui::ColorEdit3(name, components, color_flags(options));
+}
+
+extern "C" bool ImGui_ColorButton(const char* name, ImGui_Vec4 color, int options, ImGui_Vec2 size) {
+    ImVec4 col(color.x, color.y, color.z, color.w);
+    return ImGui::ColorButton(name, col, color_flags(options), ImVec2(size.x, size.y));
 }
 
 extern "C" void ImGui_SameLine(float offset_from_start_x, float spacing) {
     ImGui::SameLine(offset_from_start_x, spacing);
 }
 
-extern "C" void ImGui_SliderInt(const char* label, int* value, int min_val, int max_val) {
-    ImGui::SliderInt(label, value, min_val, max_val);
+//has to match the slider options in src/backend.rs
+enum SliderOption {
+    SliderOption_AlwaysClamp = 1 << 0,
+    SliderOption_Logarithmic = 1 << 1,
+    SliderOption_NoInput = 1 << 2,
+};
+
+static ImGuiSliderFlags slider_flags(int options) {
+    ImGuiSliderFlags flags = 0;
+    if (options & SliderOption_AlwaysClamp) flags |= ImGuiSliderFlags_AlwaysClamp;
+    if (options & SliderOption_Logarithmic) flags |= ImGuiSliderFlags_Logarithmic;
+    if (options & SliderOption_NoInput) flags |= ImGuiSliderFlags_NoInput;
+    return flags;
 }
 
-extern "C" void ImGui_SliderFloat(const char* label, float* value, float v_min, float v_max) {
-    ImGui::SliderFloat(label, value, v_min, v_max, "%.3f");
+extern "C" void ImGui_SliderInt(const char* label, int* value, int min_val, int max_val, const char* format, int options, ImGui_Vec2 vertical_size) {
+    if (vertical_size.x > 0.0f && vertical_size.y > 0.0f)
+        ImGui::VSliderInt(label, ImVec2(vertical_size.x, vertical_size.y), value, min_val, max_val, format, slider_flags(options));
+    else
+        ImGui::SliderInt(label, value, min_val, max_val, format, slider_flags(options));
+}
+
+extern "C" void ImGui_SliderFloat(const char* label, float* value, float v_min, float v_max, const char* format, int options, ImGui_Vec2 vertical_size) {
+    if (vertical_size.x > 0.0f && vertical_size.y > 0.0f)
+        ImGui::VSliderFloat(label, ImVec2(vertical_size.x, vertical_size.y), value, v_min, v_max, format, slider_flags(options));
+    else
+        ImGui::SliderFloat(label, value, v_min, v_max, format, slider_flags(options));
+}
+
+extern "C" void ImGui_SliderAngle(const char* label, float* value, float min_degrees, float max_degrees, const char* format, int options) {
+    ImGui::SliderAngle(label, value, min_degrees, max_degrees, format, slider_flags(options));
 }
 
 extern "C" void show_demo_window() {
     ImGui::ShowDemoWindow();
 }
 
-extern "C" void ImGui_InputText(const char* label, char * buffer, int buffersize, int flags) {
-    ImGui::InputText(label, buffer, buffersize, flags);
+//counterpart of InputTextEdit in src/backend.rs
+extern "C" struct InputTextEdit {
+    size_t start;
+    size_t end;
+    const char* text;
+    size_t len;
+};
+
+//counterpart of InputTextHandler in src/backend.rs
+extern "C" struct InputTextHandler {
+    void* data;
+    char* (*resize)(void* data, size_t size);
+    bool (*edit)(void* data, int event, const char* text, size_t len, size_t cursor, InputTextEdit* edit);
+};
+
+//has to match the events passed to InputTextHandler::edit in src/backend.rs
+enum InputTextEditEvent {
+    InputTextEditEvent_HistoryPrevious = 1,
+    InputTextEditEvent_HistoryNext = 2,
+    InputTextEditEvent_Completion = 3,
+};
+
+struct InputTextState {
+    InputTextHandler* handler;
+    //text set from Rust which replaces the text of an active edit
+    const char* replace;
+};
+
+static int input_text_callback(ImGuiInputTextCallbackData* data) {
+    InputTextState* state = (InputTextState*)data->UserData;
+    if (data->EventFlag == ImGuiInputTextFlags_CallbackResize) {
+        data->Buf = state->handler->resize(state->handler->data, (size_t)data->BufSize);
+        return 0;
+    }
+
+    //Dear ImGui only fires one event per frame, so any event other than resize applies the replacement
+    //before the event itself is handled on the replaced text
+    if (state->replace != NULL) {
+        data->DeleteChars(0, data->BufTextLen);
+        data->InsertChars(0, state->replace);
+        state->replace = NULL;
+    }
+
+    int event = 0;
+    if (data->EventFlag == ImGuiInputTextFlags_CallbackHistory)
+        event = data->EventKey == ImGuiKey_UpArrow ? InputTextEditEvent_HistoryPrevious : InputTextEditEvent_HistoryNext;
+    else if (data->EventFlag == ImGuiInputTextFlags_CallbackCompletion)
+        event = InputTextEditEvent_Completion;
+
+    InputTextEdit edit = { 0, 0, NULL, 0 };
+    if (event != 0 && state->handler->edit(state->handler->data, event, data->Buf, (size_t)data->BufTextLen, (size_t)data->CursorPos, &edit)) {
+        data->DeleteChars((int)edit.start, (int)(edit.end - edit.start));
+        data->InsertChars((int)edit.start, edit.text, edit.text + edit.len);
+    }
+    return 0;
+}
+
+//has to match the InputText options in src/backend.rs
+enum InputTextOption {
+    InputTextOption_CharsDecimal = 1 << 0,
+    InputTextOption_CharsHexadecimal = 1 << 1,
+    InputTextOption_CharsUppercase = 1 << 2,
+    InputTextOption_CharsNoBlank = 1 << 3,
+    InputTextOption_AutoSelectAll = 1 << 4,
+    InputTextOption_EnterReturnsTrue = 1 << 5,
+    InputTextOption_ReadOnly = 1 << 6,
+    InputTextOption_Password = 1 << 7,
+    InputTextOption_CallbackHistory = 1 << 8,
+    InputTextOption_CallbackCompletion = 1 << 9,
+};
+
+//has to match the InputText events in src/backend.rs
+enum InputTextEvent {
+    InputTextEvent_Edited = 1 << 0,
+    InputTextEvent_Submitted = 1 << 1,
+};
+
+static ImGuiInputTextFlags input_text_flags(int options) {
+    ImGuiInputTextFlags flags = ImGuiInputTextFlags_CallbackResize;
+    if (options & InputTextOption_CharsDecimal) flags |= ImGuiInputTextFlags_CharsDecimal;
+    if (options & InputTextOption_CharsHexadecimal) flags |= ImGuiInputTextFlags_CharsHexadecimal;
+    if (options & InputTextOption_CharsUppercase) flags |= ImGuiInputTextFlags_CharsUppercase;
+    if (options & InputTextOption_CharsNoBlank) flags |= ImGuiInputTextFlags_CharsNoBlank;
+    if (options & InputTextOption_AutoSelectAll) flags |= ImGuiInputTextFlags_AutoSelectAll;
+    if (options & InputTextOption_EnterReturnsTrue) flags |= ImGuiInputTextFlags_EnterReturnsTrue;
+    if (options & InputTextOption_ReadOnly) flags |= ImGuiInputTextFlags_ReadOnly;
+    if (options & InputTextOption_Password) flags |= ImGuiInputTextFlags_Password;
+    if (options & InputTextOption_CallbackHistory) flags |= ImGuiInputTextFlags_CallbackHistory;
+    if (options & InputTextOption_CallbackCompletion) flags |= ImGuiInputTextFlags_CallbackCompletion;
+    return flags;
+}
+
+extern "C" int ImGui_InputText(const char* label, const char* hint, char* buffer, size_t buffer_size, int options, bool multiline, float width, float height, bool replace_active, InputTextHandler* handler) {
+    InputTextState state = { handler, replace_active ? buffer : NULL };
+    ImGuiInputTextFlags flags = input_text_flags(options);
+    if (replace_active)
+        flags |= ImGuiInputTextFlags_CallbackAlways;
+
+    bool result;
+    if (multiline)
+        result = ImGui::InputTextMultiline(label, buffer, buffer_size, ImVec2(width, height), flags, input_text_callback, &state);
+    else if (hint != NULL)
+        result = ImGui::InputTextWithHint(label, hint, buffer, buffer_size, flags, input_text_callback, &state);
+    else
+        result = ImGui::InputText(label, buffer, buffer_size, flags, input_text_callback, &state);
+
+    int events = ImGui::IsItemEdited() ? InputTextEvent_Edited : 0;
+    if (result && (options & InputTextOption_EnterReturnsTrue))
+        events |= InputTextEvent_Submitted;
+    return events;
+}
+
+extern "C" void ImGui_Separator() {
+    ImGui::Separator();
+}
+
+extern "C" void ImGui_Spacing() {
+    ImGui::Spacing();
+}
+
+extern "C" void ImGui_Indent(float indent_w) {
+    ImGui::Indent(indent_w);
+}
+
+extern "C" void ImGui_Unindent(float indent_w) {
+    ImGui::Unindent(indent_w);
+}
+
+extern "C" void ImGui_Bullet() {
+    ImGui::Bullet();
+}
+
+extern "C" void ImGui_PushHeadingFont(int level) {
+    ImGui::PushFont(heading_fonts[level < 1 ? 0 : (level > 3 ? 2 : level - 1)]);
+}
+
+extern "C" void ImGui_PopFont() {
+    ImGui::PopFont();
+}
+
+//has to match the style bits in src/markdown.rs
+enum MarkdownStyle {
+    MarkdownStyle_Bold = 1 << 0,
+    MarkdownStyle_Italic = 1 << 1,
+    MarkdownStyle_Code = 1 << 2,
+    MarkdownStyle_Link = 1 << 3,
+};
+
+static bool markdown_word(const char* begin, const char* end, int style) {
+    ImDrawList* draw_list = ImGui::GetWindowDrawList();
+    ImVec2 pos = ImGui::GetCursorScreenPos();
+    ImVec2 size = ImGui::CalcTextSize(begin, end);
+    ImU32 color = ImGui::GetColorU32((style & MarkdownStyle_Link) ? ImGuiCol_ButtonHovered : ImGuiCol_Text);
+
+    if (style & MarkdownStyle_Code)
+        draw_list->AddRectFilled(pos, ImVec2(pos.x + size.x, pos.y + size.y), ImGui::GetColorU32(ImGuiCol_FrameBg), 2.0f);
+
+    int vtx_begin = draw_list->VtxBuffer.Size;
+    ImGui::PushStyleColor(ImGuiCol_Text, color);
+    ImGui::TextUnformatted(begin, end);
+    ImGui::PopStyleColor();
+    //the default font has no bold or italic variant, so the glyphs are drawn twice or slanted instead
+    if (style & MarkdownStyle_Bold)
+        draw_list->AddText(ImVec2(pos.x + 1.0f, pos.y), color, begin, end);
+    if (style & MarkdownStyle_Italic)
+        for (int i = vtx_begin; i < draw_list->VtxBuffer.Size; i++)
+            draw_list->VtxBuffer[i].pos.x += (pos.y + size.y - draw_list->VtxBuffer[i].pos.y) * 0.2f;
+
+    if (!(style & MarkdownStyle_Link))
+        return false;
+    if (ImGui::IsItemHovered()) {
+        ImGui::SetMouseCursor(ImGuiMouseCursor_Hand);
+        draw_list->AddLine(ImVec2(pos.x, pos.y + size.y), ImVec2(pos.x + size.x, pos.y + size.y), color);
+    }
+    return ImGui::IsItemClicked();
+}
+
+//renders the text word by word so that runs with different styles wrap like one paragraph.
+//returns true if the text is a link and got clicked.
+extern "C" bool ImGui_MarkdownText(const char* text, size_t len, int style, bool same_line) {
+    const char* end = text + len;
+    bool clicked = false;
+    while (text < end) {
+        const char* word_end = text;
+        while (word_end < end && *word_end == ' ')
+            word_end++;
+        while (word_end < end && *word_end != ' ')
+            word_end++;
+
+        if (same_line) {
+            ImGui::SameLine(0.0f, 0.0f);
+            if (ImGui::CalcTextSize(text, word_end).x > ImGui::GetContentRegionAvail().x) {
+                ImGui::NewLine();
+                while (text < word_end && *text == ' ')
+                    text++;
+            }
+        }
+        if (text < word_end && markdown_word(text, word_end, style))
+            clicked = true;
+        same_line = true;
+        text = word_end;
+    }
+    return clicked;
+}
+
+extern "C" void ImGui_MarkdownCodeBlock(const char* text, size_t len) {
+    ImGuiStyle& style = ImGui::GetStyle();
+    ImVec2 pos = ImGui::GetCursorScreenPos();
+    ImVec2 text_size = ImGui::CalcTextSize(text, text + len);
+    ImVec2 size(text_size.x + style.FramePadding.x * 2.0f, text_size.y + style.FramePadding.y * 2.0f);
+    if (size.x < ImGui::GetContentRegionAvail().x)
+        size.x = ImGui::GetContentRegionAvail().x;
+
+    ImGui::GetWindowDrawList()->AddRectFilled(pos, ImVec2(pos.x + size.x, pos.y + size.y), ImGui::GetColorU32(ImGuiCol_FrameBg), style.FrameRounding);
+    ImGui::SetCursorScreenPos(ImVec2(pos.x + style.FramePadding.x, pos.y + style.FramePadding.y));
+    ImGui::TextUnformatted(text, text + len);
+    ImGui::SetCursorScreenPos(pos);
+    ImGui::Dummy(size);
+}
+
+// a negative height leaves that much space below the child window
+extern "C" bool ImGui_BeginChild(const char* id, float width, float height, bool border) {
+    return ImGui::BeginChild(id, ImVec2(width, height), border);
+}
+
+extern "C" void ImGui_EndChild() {
+    ImGui::EndChild();
+}
+
+extern "C" float ImGui_GetFrameHeightWithSpacing() {
+    return ImGui::GetFrameHeightWithSpacing();
+}
+
+extern "C" float ImGui_GetScrollY() {
+    return ImGui::GetScrollY();
+}
+
+extern "C" float ImGui_GetScrollMaxY() {
+    return ImGui::GetScrollMaxY();
+}
+
+extern "C" void ImGui_SetScrollHereY(float center_y_ratio) {
+    ImGui::SetScrollHereY(center_y_ratio);
+}
+
+extern "C" void ImGui_SetKeyboardFocusHere(int offset) {
+    ImGui::SetKeyboardFocusHere(offset);
+}
+
+extern "C" void ImGui_NewLine() {
+    ImGui::NewLine();
+}
+
+extern "C" void ImGui_Dummy(ImGui_Vec2 size) {
+    ImGui::Dummy(ImVec2(size.x, size.y));
+}
+
+extern "C" void ImGui_BeginGroup() {
+    ImGui::BeginGroup();
+}
+
+extern "C" void ImGui_EndGroup() {
+    ImGui::EndGroup();
+}
+
+extern "C" void ImGui_SetNextItemWidth(float width) {
+    ImGui::SetNextItemWidth(width);
+}
+
+extern "C" void ImGui_PushItemWidth(float width) {
+    ImGui::PushItemWidth(width);
+}
+
+extern "C" void ImGui_PopItemWidth() {
+    ImGui::PopItemWidth();
+}
+
+// moves the cursor so an item of the width is placed at the alignment inside the available width
+extern "C" void ImGui_AlignNextItem(float width, float alignment) {
+    float offset = (ImGui::GetContentRegionAvail().x - width) * alignment;
+    if (offset > 0.0f)
+        ImGui::SetCursorPosX(ImGui::GetCursorPosX() + offset);
+}
+
+extern "C" float ImGui_GetItemRectWidth() {
+    return ImGui::GetItemRectSize().x;
+}
+
+extern "C" bool ImGui_InputScalarN(const char* label, ImGuiDataType data_type, void* data, int components, const void* step, const void* step_fast, const char* format) {
+    return ImGui::InputScalarN(label, data_type, data, components, step, step_fast, format);
+}
+
+extern "C" bool ImGui_DragScalarN(const char* label, ImGuiDataType data_type, void* data, int components, float speed, const void* min, const void* max, const char* format) {
+    return ImGui::DragScalarN(label, data_type, data, components, speed, min, max, format);
+}
+
+//data points to the lower and upper value of the range, only int and float are supported
+extern "C" bool ImGui_DragRange2(const char* label, ImGuiDataType data_type, void* data, float speed, const void* min, const void* max, const char* format) {
+    if (data_type == ImGuiDataType_S32) {
+        int* values = (int*)data;
+        return ImGui::DragIntRange2(label, &values[0], &values[1], speed, *(const int*)min, *(const int*)max, format);
+    }
+    float* values = (float*)data;
+    return ImGui::DragFloatRange2(label, &values[0], &values[1], speed, *(const float*)min, *(const float*)max, format);
 }
 
 extern "C" bool ImGUI_TreeNodeEx(const char* label, ImGuiTreeNodeFlags flags) {
@@ -0,0 +1,63 @@
+// wrappers of ImPlot, only compiled with the cargo feature implot
+
+#include "imgui.h"
+#include "implot.h"
+
+//acts as replacement of the ImVec2 struct
+extern "C" struct ImGui_Vec2 {
+    float x;
+    float y;
+};
+
+//has to match the plot options in src/backend.rs
+enum PlotOption {
+    PlotOption_NoLegend = 1 << 0,
+    PlotOption_Equal = 1 << 1,
+};
+
+//has to match the axes in src/implot.rs
+enum PlotAxis {
+    PlotAxis_X = 0,
+    PlotAxis_Y = 1,
+};
+
+extern "C" bool ImPlot_BeginPlot(const char* title, ImGui_Vec2 size, int options) {
+    ImPlotFlags flags = 0;
+    if (options & PlotOption_NoLegend) flags |= ImPlotFlags_NoLegend;
+    if (options & PlotOption_Equal) flags |= ImPlotFlags_Equal;
+    return ImPlot::BeginPlot(title, ImVec2(size.x, size.y), flags);
+}
+
+extern "C" void ImPlot_EndPlot() {
+    ImPlot::EndPlot();
+}
+
+// label and format are null if not set, has to be called after BeginPlot and before the first series
+extern "C" void ImPlot_SetupAxis(int axis, const char* label, const char* format, bool time, bool has_limits, double min, double max) {
+    ImAxis im_axis = axis == PlotAxis_X ? ImAxis_X1 : ImAxis_Y1;
+    ImPlot::SetupAxis(im_axis, label);
+    if (format != nullptr)
+        ImPlot::SetupAxisFormat(im_axis, format);
+    if (time)
+        ImPlot::SetupAxisScale(im_axis, ImPlotScale_Time);
+    if (has_limits)
+        ImPlot::SetupAxisLimits(im_axis, min, max, ImPlotCond_Once);
+}
+
+// the points are interleaved x and y coordinates
+extern "C" void ImPlot_PlotLine(const char* label, const double* points, size_t count) {
+    ImPlot::PlotLine(label, &points[0], &points[1], (int)count, 0, 0, 2 * sizeof(double));
+}
+
+extern "C" void ImPlot_PlotScatter(const char* label, const double* points, size_t count) {
+    ImPlot::PlotScatter(label, &points[0], &points[1], (int)count, 0, 0, 2 * sizeof(double));
+}
+
+extern "C" void ImPlot_PlotBars(const char* label, const double* points, size_t count, double width) {
+    ImPlot::PlotBars(label, &points[0], &points[1], (int)count, width, 0, 0, 2 * sizeof(double));
+}
+
+// the values are row major, the labels of the cells are hidden
+extern "C" void ImPlot_PlotHeatmap(const char* label, const double* values, size_t rows, size_t columns, double scale_min, double scale_max) {
+    ImPlot::PlotHeatmap(label, values, (int)rows, (int)columns, scale_min, scale_max, nullptr);
+}
@@ -0,0 +1,277 @@
+use crate::backend::*;
+use crate::{Gui, Update};
+use std::{any::Any, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+//has to match the axes in implot_lib.cpp
+const AXIS_X: i32 = 0;
+const AXIS_Y: i32 = 1;
+
+/// Appends the null terminator expected by ImPlot.
+fn c_string(text: &str) -> String {
+    let mut text = String::from_str(text).unwrap();
+    if !text.ends_with('\0') {
+        text.push('\0');
+    };
+    text
+}
+
+/// Points of a line, scatter or bar series. Shared with the code producing the data, so it can
+/// be updated from any thread.
+pub type Points = Arc<RwLock<Vec<[f64; 2]>>>;
+
+/// Row major values of a heatmap.
+pub type Grid = Arc<RwLock<Vec<f64>>>;
+
+#[derive(Clone)]
+enum SeriesKind {
+    Line(Points),
+    Scatter(Points),
+    Bars(Points, f64),
+    Heatmap {
+        values: Grid,
+        rows: usize,
+        columns: usize,
+        scale: (f64, f64),
+    },
+}
+
+/// A data set drawn by a `Plot`. The label is shown in the legend.
+#[derive(Clone)]
+pub struct Series {
+    label: String,
+    kind: SeriesKind,
+}
+
+impl Series {
+    pub fn line(label: &str, points: Points) -> Self {
+        Series {
+            label: c_string(label),
+            kind: SeriesKind::Line(points),
+        }
+    }
+
+    pub fn scatter(label: &str, points: Points) -> Self {
+        Series {
+            label: c_string(label),
+            kind: SeriesKind::Scatter(points),
+        }
+    }
+
+    /// Bars centered at the x coordinates of the points, `width` is in plot units.
+    pub fn bars(label: &str, points: Points, width: f64) -> Self {
+        Series {
+            label: c_string(label),
+            kind: SeriesKind::Bars(points, width),
+        }
+    }
+
+    /// Colors `rows * columns` values in the unit square, a scale of (0.0, 0.0) scales to the values.
+    pub fn heatmap(
+        label: &str,
+        values: Grid,
+        rows: usize,
+        columns: usize,
+        scale: (f64, f64),
+    ) -> Self {
+        Series {
+            label: c_string(label),
+            kind: SeriesKind::Heatmap {
+                values,
+                rows,
+                columns,
+                scale,
+            },
+        }
+    }
+
+    fn draw(&self) {
+        let label = self.label.as_ptr();
+        unsafe {
+            match &self.kind {
+                SeriesKind::Line(points) => {
+                    let points = points.blocking_read();
+                    ImPlot_PlotLine(label, points.as_ptr().cast(), points.len())
+                }
+                SeriesKind::Scatter(points) => {
+                    let points = points.blocking_read();
+                    ImPlot_PlotScatter(label, points.as_ptr().cast(), points.len())
+                }
+                SeriesKind::Bars(points, width) => {
+                    let points = points.blocking_read();
+                    ImPlot_PlotBars(label, points.as_ptr().cast(), points.len(), *width)
+                }
+                SeriesKind::Heatmap {
+                    values,
+                    rows,
+                    columns,
+                    scale,
+                } => {
+                    let values = values.blocking_read();
+                    //never read past the end if the values got resized
+                    let rows = (*rows).min(values.len() / (*columns).max(1));
+                    ImPlot_PlotHeatmap(label, values.as_ptr(), rows, *columns, scale.0, scale.1)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Axis {
+    label: Option<String>,
+    format: Option<String>,
+    time: bool,
+    limits: Option<(f64, f64)>,
+}
+
+impl Axis {
+    fn setup(&self, axis: i32) {
+        let label = self.label.as_ref().map_or(std::ptr::null(), |l| l.as_ptr());
+        let format = self
+            .format
+            .as_ref()
+            .map_or(std::ptr::null(), |f| f.as_ptr());
+        let (min, max) = self.limits.unwrap_or((0.0, 0.0));
+        unsafe {
+            ImPlot_SetupAxis(
+                axis,
+                label,
+                format,
+                self.time,
+                self.limits.is_some(),
+                min,
+                max,
+            )
+        }
+    }
+}
+
+/// A chart drawn with ImPlot, only available with the feature `implot`.
+///
+/// Drag to pan, scroll to zoom and double click to fit the data. Series can be added at runtime.
+#[derive(Clone)]
+pub struct Plot {
+    title: Arc<RwLock<String>>,
+    series: Arc<RwLock<Vec<Series>>>,
+    x_axis: Arc<RwLock<Axis>>,
+    y_axis: Arc<RwLock<Axis>>,
+    size: Arc<RwLock<ImGui_Vec2>>,
+    flags: Arc<RwLock<i32>>,
+}
+
+impl Plot {
+    pub fn new(title: &str) -> Self {
+        Plot {
+            title: Arc::new(RwLock::new(c_string(title))),
+            series: Arc::new(RwLock::new(vec![])),
+            x_axis: Arc::new(RwLock::new(Axis::default())),
+            y_axis: Arc::new(RwLock::new(Axis::default())),
+            //a negative width fills the available width
+            size: Arc::new(RwLock::new(ImGui_Vec2 { x: -1.0, y: 0.0 })),
+            flags: Arc::new(RwLock::new(0)),
+        }
+    }
+
+    pub fn series(self, series: Series) -> Self {
+        self.add_series(series);
+        self
+    }
+
+    /// Adds a series, can be called from any thread.
+    pub fn add_series(&self, series: Series) {
+        self.series.blocking_write().push(series);
+    }
+
+    /// Removes all series with the label.
+    pub fn remove_series(&self, label: &str) {
+        let label = c_string(label);
+        self.series.blocking_write().retain(|s| s.label != label);
+    }
+
+    pub fn x_label(self, label: &str) -> Self {
+        self.x_axis.blocking_write().label = Some(c_string(label));
+        self
+    }
+
+    pub fn y_label(self, label: &str) -> Self {
+        self.y_axis.blocking_write().label = Some(c_string(label));
+        self
+    }
+
+    /// printf style format of the tick labels, e.g. `"%.1f s"` to show a unit.
+    pub fn x_format(self, format: &str) -> Self {
+        self.x_axis.blocking_write().format = Some(c_string(format));
+        self
+    }
+
+    /// printf style format of the tick labels, e.g. `"%.0f °C"` to show a unit.
+    pub fn y_format(self, format: &str) -> Self {
+        self.y_axis.blocking_write().format = Some(c_string(format));
+        self
+    }
+
+    /// Interprets x values as UNIX timestamps in seconds and shows dates and times.
+    pub fn time_x_axis(self) -> Self {
+        self.x_axis.blocking_write().time = true;
+        self
+    }
+
+    /// The initial range of the x axis, the user can still zoom and pan.
+    pub fn x_limits(self, min: f64, max: f64) -> Self {
+        self.x_axis.blocking_write().limits = Some((min, max));
+        self
+    }
+
+    /// The initial range of the y axis, the user can still zoom and pan.
+    pub fn y_limits(self, min: f64, max: f64) -> Self {
+        self.y_axis.blocking_write().limits = Some((min, max));
+        self
+    }
+
+    pub fn no_legend(self) -> Self {
+        *self.flags.blocking_write() |= PLOT_NO_LEGEND;
+        self
+    }
+
+    /// Uses the same scale for both axes.
+    pub fn equal_axes(self) -> Self {
+        *self.flags.blocking_write() |= PLOT_EQUAL;
+        self
+    }
+
+    /// A width of zero uses the default width, a negative width fills the available width
+    /// except the given amount. A height of zero uses the default height.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        *self.size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
+}
+
+impl Update for Plot {
+    fn update(&self, _gui: &Gui) -> bool {
+        if !unsafe {
+            ImPlot_BeginPlot(
+                self.title.blocking_read().as_ptr(),
+                *self.size.blocking_read(),
+                *self.flags.blocking_read(),
+            )
+        } {
+            return false;
+        }
+        self.x_axis.blocking_read().setup(AXIS_X);
+        self.y_axis.blocking_read().setup(AXIS_Y);
+        for series in self.series.blocking_read().iter() {
+            series.draw();
+        }
+        unsafe { ImPlot_EndPlot() }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
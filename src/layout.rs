@@ -0,0 +1,455 @@
+use crate::backend::*;
+use crate::{Container2, Get, Gui, Set, Update};
+use rust_gui_macros::impl_Update;
+use std::{any::Any, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+//the columns of a Columns container have no header
+const NO_HEADER: &str = "\0";
+
+/// A horizontal line.
+#[derive(Clone, Default)]
+pub struct Separator;
+
+impl Separator {
+    pub fn new() -> Self {
+        Separator
+    }
+}
+
+impl_Update!(Separator, ImGui_Separator());
+
+/// A small vertical gap.
+#[derive(Clone, Default)]
+pub struct Spacing;
+
+impl Spacing {
+    pub fn new() -> Self {
+        Spacing
+    }
+}
+
+impl_Update!(Spacing, ImGui_Spacing());
+
+/// Ends a line of widgets placed with `same_line`, or adds an empty line.
+#[derive(Clone, Default)]
+pub struct NewLine;
+
+impl NewLine {
+    pub fn new() -> Self {
+        NewLine
+    }
+}
+
+impl_Update!(NewLine, ImGui_NewLine());
+
+/// Empty space of the given size.
+#[derive(Clone)]
+pub struct Dummy {
+    size: Arc<RwLock<ImGui_Vec2>>,
+}
+
+impl Dummy {
+    pub fn new(width: f32, height: f32) -> Self {
+        Dummy {
+            size: Arc::new(RwLock::new(ImGui_Vec2 {
+                x: width,
+                y: height,
+            })),
+        }
+    }
+}
+
+/// The width and height.
+impl Set<(f32, f32)> for Dummy {
+    fn set(&self, value: (f32, f32)) {
+        *self.size.blocking_write() = ImGui_Vec2 {
+            x: value.0,
+            y: value.1,
+        };
+    }
+}
+
+impl Get<(f32, f32)> for Dummy {
+    fn get(&self) -> (f32, f32) {
+        let size = self.size.blocking_read();
+        (size.x, size.y)
+    }
+}
+
+impl_Update!(Dummy, ImGui_Dummy(*self.size.blocking_read()));
+
+/// Moves its widgets to the right. A width of zero uses the indent spacing of the style.
+#[derive(Clone)]
+pub struct Indent {
+    width: Arc<RwLock<f32>>,
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl Indent {
+    pub fn new(width: f32) -> Self {
+        Indent {
+            width: Arc::new(RwLock::new(width)),
+            items: vec![],
+        }
+    }
+}
+
+impl Container2 for Indent {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for Indent {
+    fn update(&self, gui: &Gui) -> bool {
+        let width = *self.width.blocking_read();
+        unsafe { ImGui_Indent(width) }
+        for widget in &self.items {
+            if widget.update(gui) {
+                widget.call_callback(gui);
+            }
+        }
+        unsafe { ImGui_Unindent(width) }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Moves its widgets to the left, e.g. out of the indentation of a `TreeNode`. A width of zero
+/// uses the indent spacing of the style.
+#[derive(Clone)]
+pub struct Unindent {
+    width: Arc<RwLock<f32>>,
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl Unindent {
+    pub fn new(width: f32) -> Self {
+        Unindent {
+            width: Arc::new(RwLock::new(width)),
+            items: vec![],
+        }
+    }
+}
+
+impl Container2 for Unindent {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for Unindent {
+    fn update(&self, gui: &Gui) -> bool {
+        let width = *self.width.blocking_read();
+        unsafe { ImGui_Unindent(width) }
+        for widget in &self.items {
+            if widget.update(gui) {
+                widget.call_callback(gui);
+            }
+        }
+        unsafe { ImGui_Indent(width) }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Lays out its widgets as one item, so a group of widgets can be placed with `same_line` or
+/// get a tooltip.
+#[derive(Clone)]
+pub struct Group {
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl Group {
+    pub fn new() -> Self {
+        Group { items: vec![] }
+    }
+}
+
+impl Default for Group {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Container2 for Group {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for Group {
+    fn update(&self, gui: &Gui) -> bool {
+        unsafe { ImGui_BeginGroup() }
+        for widget in &self.items {
+            if widget.update(gui) {
+                widget.call_callback(gui);
+            }
+        }
+        unsafe { ImGui_EndGroup() }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A widget with a fixed width, created with `.item_width(width)` or `.push_item_width(width)` on
+/// any widget.
+///
+/// A positive width is in pixels, a negative width leaves that much space to the right edge,
+/// so `-f32::MIN_POSITIVE` fills the available width. Behaves like the wrapped widget, `gui.get`
+/// and `gui.set` find it by the type of the wrapped widget.
+#[derive(Clone)]
+pub struct WithItemWidth<W: Update> {
+    widget: W,
+    width: Arc<RwLock<f32>>,
+    //applies to all widgets of a container instead of only the next one
+    push: bool,
+}
+
+impl<W: Update> WithItemWidth<W> {
+    /// Sets the width of the widget.
+    pub fn new(widget: W, width: f32) -> Self {
+        WithItemWidth {
+            widget,
+            width: Arc::new(RwLock::new(width)),
+            push: false,
+        }
+    }
+
+    /// Sets the width of all widgets inside the container.
+    pub fn push(widget: W, width: f32) -> Self {
+        WithItemWidth {
+            push: true,
+            ..WithItemWidth::new(widget, width)
+        }
+    }
+
+    pub fn set_width(&self, width: f32) {
+        *self.width.blocking_write() = width;
+    }
+}
+
+impl<W: Update + Get<T>, T> Get<T> for WithItemWidth<W> {
+    fn get(&self) -> T {
+        self.widget.get()
+    }
+}
+
+impl<W: Update + Set<T>, T> Set<T> for WithItemWidth<W> {
+    fn set(&self, value: T) {
+        self.widget.set(value)
+    }
+}
+
+impl<W: Update + Container2> Container2 for WithItemWidth<W> {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        self.widget.get_items()
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        self.widget.get_mut_items()
+    }
+}
+
+impl<W: Update> Update for WithItemWidth<W> {
+    fn update(&self, gui: &Gui) -> bool {
+        let width = *self.width.blocking_read();
+        if self.push {
+            unsafe { ImGui_PushItemWidth(width) }
+            let changed = self.widget.update(gui);
+            unsafe { ImGui_PopItemWidth() }
+            changed
+        } else {
+            unsafe { ImGui_SetNextItemWidth(width) }
+            self.widget.update(gui)
+        }
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        self.widget.call_callback(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(self, callback: F) -> Self {
+        WithItemWidth {
+            widget: self.widget.set_callback(callback),
+            ..self
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.widget.as_any()
+    }
+}
+
+/// Lays out its widgets in rows of `columns` cells, filled from left to right. Built on a table
+/// without borders, so the columns line up across the rows, e.g. the labels and inputs of a form.
+///
+/// The columns share the available width unless they get a fixed width.
+#[derive(Clone)]
+pub struct Columns {
+    id: Arc<RwLock<String>>,
+    //the column options and widths passed to TableSetupColumn
+    columns: Arc<RwLock<Vec<(i32, f32)>>>,
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl Columns {
+    pub fn new(id: &str, columns: usize) -> Self {
+        let mut id = String::from_str(id).unwrap();
+        if !id.ends_with('\0') {
+            id.push('\0');
+        };
+
+        Columns {
+            id: Arc::new(RwLock::new(id)),
+            columns: Arc::new(RwLock::new(vec![(0, 0.0); columns])),
+            items: vec![],
+        }
+    }
+
+    /// A fixed width in pixels for the column.
+    pub fn column_width(self, column: usize, width: f32) -> Self {
+        self.set_column(column, COLUMN_WIDTH_FIXED, width)
+    }
+
+    /// Shares the available width with the other stretched columns, weighted by `weight`.
+    pub fn column_stretch(self, column: usize, weight: f32) -> Self {
+        self.set_column(column, COLUMN_WIDTH_STRETCH, weight)
+    }
+
+    fn set_column(self, column: usize, flags: i32, width: f32) -> Self {
+        if let Some(setup) = self.columns.blocking_write().get_mut(column) {
+            *setup = (flags, width);
+        }
+        self
+    }
+}
+
+impl Container2 for Columns {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for Columns {
+    fn update(&self, gui: &Gui) -> bool {
+        let columns = self.columns.blocking_read().clone();
+        if columns.is_empty()
+            || !unsafe {
+                ImGui_BeginTable(
+                    self.id.blocking_read().as_ptr(),
+                    columns.len() as i32,
+                    0,
+                    ImGui_Vec2::default(),
+                )
+            }
+        {
+            return false;
+        }
+
+        for (flags, width) in columns.iter() {
+            unsafe { ImGui_TableSetupColumn(NO_HEADER.as_ptr(), *flags, *width) }
+        }
+        for (i, widget) in self.items.iter().enumerate() {
+            let column = i % columns.len();
+            if column == 0 {
+                unsafe { ImGui_TableNextRow() }
+            }
+            if unsafe { ImGui_TableSetColumnIndex(column as i32) } && widget.update(gui) {
+                widget.call_callback(gui);
+            }
+        }
+        unsafe { ImGui_EndTable() }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A widget aligned inside the available width, created with `.align_right()`, `.center()` or
+/// `.align(alignment)` on any widget.
+///
+/// The width of the widget is measured when it is drawn, so it is aligned from the second frame
+/// on. Behaves like the wrapped widget, `gui.get` and `gui.set` find it by the type of the
+/// wrapped widget.
+#[derive(Clone)]
+pub struct Aligned<W: Update> {
+    widget: W,
+    //0.0 aligns to the left, 0.5 centers and 1.0 aligns to the right
+    alignment: f32,
+    width: Arc<RwLock<f32>>,
+}
+
+impl<W: Update> Aligned<W> {
+    pub fn new(widget: W, alignment: f32) -> Self {
+        Aligned {
+            widget,
+            alignment: alignment.clamp(0.0, 1.0),
+            width: Arc::new(RwLock::new(0.0)),
+        }
+    }
+}
+
+impl<W: Update + Get<T>, T> Get<T> for Aligned<W> {
+    fn get(&self) -> T {
+        self.widget.get()
+    }
+}
+
+impl<W: Update + Set<T>, T> Set<T> for Aligned<W> {
+    fn set(&self, value: T) {
+        self.widget.set(value)
+    }
+}
+
+impl<W: Update> Update for Aligned<W> {
+    fn update(&self, gui: &Gui) -> bool {
+        unsafe { ImGui_AlignNextItem(*self.width.blocking_read(), self.alignment) }
+        let changed = self.widget.update(gui);
+        *self.width.blocking_write() = unsafe { ImGui_GetItemRectWidth() };
+        changed
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        self.widget.call_callback(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(self, callback: F) -> Self {
+        Aligned {
+            widget: self.widget.set_callback(callback),
+            ..self
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.widget.as_any()
+    }
+}
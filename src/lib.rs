@@ -1,23 +1,65 @@
 mod backend;
+mod color;
+mod console;
+mod dialog;
+#[cfg(feature = "implot")]
+mod implot;
+mod layout;
+mod markdown;
+mod menu;
+mod plot;
+mod popup;
+mod progress;
+mod selection;
+mod table;
+mod tabs;
+mod tooltip;
 use rust_gui_macros::*;
 
 use backend::*;
+pub use color::Color;
+pub use console::Console;
 use core::panic;
+use dialog::DialogQueue;
+#[cfg(feature = "implot")]
+pub use implot::{Grid, Plot, Points, Series};
+pub use layout::{
+    Aligned, Columns, Dummy, Group, Indent, NewLine, Separator, Spacing, Unindent, WithItemWidth,
+};
+pub use markdown::Markdown;
+pub use menu::{MainMenuBar, Menu, MenuBar, MenuItem};
+pub use plot::{PlotHistogram, PlotLines, SamplePlot};
+pub use popup::{ContextMenu, Popup, PopupModal};
+pub use progress::{Progress, ProgressBar};
+pub use rust_gui_macros::GuiEnum;
+pub use rust_gui_macros::TableRow;
+pub use selection::{Combo, EnumWidget, GuiEnum, ListBox, RadioGroup, Selectable};
 use std::{
     any::Any,
     ffi::c_void,
+    fmt,
     str::FromStr,
     sync::{
         mpsc::{self, Receiver},
-        Arc,
+        Arc, Mutex,
     },
     thread::{self, JoinHandle},
 };
+pub use table::{Column, SortSpec, Table, TableData, TableRow};
+pub use tabs::{TabBar, TabItem};
 use tokio::sync::RwLock;
+pub use tooltip::{HelpMarker, Tooltip, WithTooltip};
 
 pub struct Gui {
     label: String,
     windows2: Vec<Window>,
+    main_menu_bar: Option<MainMenuBar>,
+    //ids of the popups to open, taken by the popups when they are shown, and whether a frame
+    //ended since the request
+    popup_requests: RwLock<Vec<(String, bool)>>,
+    dialog_queue: Mutex<DialogQueue>,
+    //the dialog of the queue which is currently shown
+    dialog: RwLock<Option<PopupModal>>,
     glfw_window: RwLock<Option<&'static c_void>>,
     io: RwLock<Option<&'static c_void>>,
     thread_handle: RwLock<Option<JoinHandle<()>>>,
@@ -34,6 +76,10 @@ impl Gui {
         Gui {
             label,
             windows2: vec![],
+            main_menu_bar: None,
+            popup_requests: RwLock::new(vec![]),
+            dialog_queue: Mutex::new(DialogQueue::default()),
+            dialog: RwLock::new(None),
             glfw_window: RwLock::new(None),
             io: RwLock::new(None),
             thread_handle: RwLock::new(None),
@@ -46,6 +92,37 @@ impl Gui {
         self
     }
 
+    /// Shows the menu bar at the top of the application window.
+    pub fn main_menu_bar(mut self, menu_bar: MainMenuBar) -> Self {
+        self.main_menu_bar = Some(menu_bar);
+        self
+    }
+
+    /// Opens the `Popup` or `PopupModal` with the id, can be called from callbacks and other
+    /// threads. The request is dropped if no such popup is shown in the current or next frame.
+    pub fn open_popup(&self, id: &str) {
+        self.popup_requests
+            .blocking_write()
+            .push((String::from(id), false));
+    }
+
+    fn take_popup_request(&self, id: &str) -> bool {
+        let id = id.trim_end_matches('\0');
+        let mut requests = self.popup_requests.blocking_write();
+        let requested = requests.iter().any(|(request, _)| request == id);
+        requests.retain(|(request, _)| request != id);
+        requested
+    }
+
+    //requests made during a frame may be for popups which were already drawn in it
+    fn drop_old_popup_requests(&self) {
+        let mut requests = self.popup_requests.blocking_write();
+        requests.retain(|(_, old)| !old);
+        for (_, old) in requests.iter_mut() {
+            *old = true;
+        }
+    }
+
     pub fn set<T: Set<U> + 'static, U>(&self, window_idx: usize, widget_idx: usize, value: U) {
         if let Some(window) = self.windows2.get(window_idx) {
             window.set_val::<T, U>(widget_idx, value);
@@ -74,6 +151,26 @@ impl Gui {
         Arc::new(self)
     }
 
+    //dialogs are shown one after another, opening a second modal would close the first one
+    fn update_dialog(&self) {
+        let dialog = self.dialog.blocking_read().clone();
+        let dialog = dialog.or_else(|| {
+            let builder = self.dialog_queue.lock().unwrap().pop()?;
+            let dialog = builder();
+            dialog.open();
+            *self.dialog.blocking_write() = Some(dialog.clone());
+            Some(dialog)
+        });
+        if let Some(dialog) = dialog {
+            if dialog.update(self) {
+                dialog.call_callback(self);
+            }
+            if !Get::<bool>::get(&dialog) {
+                *self.dialog.blocking_write() = None;
+            }
+        }
+    }
+
     fn should_close(&self) -> bool {
         unsafe {
             if close_window(self.glfw_window.blocking_read().unwrap()) {
@@ -85,12 +182,18 @@ impl Gui {
 
     fn update(&self) {
         unsafe { start_frame() }
+        if let Some(menu_bar) = &self.main_menu_bar {
+            menu_bar.update(self);
+        }
+        unsafe { ImGui_DockSpaceOverViewport() }
         if *self.show_demo_window.blocking_read() {
             show_demo_window();
         }
         for window in &self.windows2 {
             window.update(self);
         }
+        self.update_dialog();
+        self.drop_old_popup_requests();
         let clear_color = ImGui_Vec4 {
             x: 0.3,
             y: 0.3,
@@ -143,7 +246,15 @@ impl Start for GuiHandle {
                 // let time_delta = time::Instant::now() - start_time;
                 // println!("{:?}", time_delta);
             }
+
+            cp.dialog_queue.lock().unwrap().close();
+            //drops the sender of the shown dialog, so a thread waiting for its answer returns
+            *cp.dialog.blocking_write() = None;
         });
+        self.dialog_queue
+            .lock()
+            .unwrap()
+            .start(handle.thread().id());
         let mut h = self.thread_handle.blocking_write();
         *h = Some(handle);
         rx
@@ -167,6 +278,46 @@ pub trait Update: Send + Sync {
     fn as_any(&self) -> &dyn Any;
 }
 
+/// Decorators available on every widget.
+pub trait WidgetExt: Update + Sized {
+    /// Shows the text while the widget is hovered.
+    fn tooltip(self, text: &str) -> WithTooltip<Self> {
+        WithTooltip::new(self, Tooltip::new(text))
+    }
+
+    /// Shows the text and widgets of the tooltip while the widget is hovered.
+    fn with_tooltip(self, tooltip: Tooltip) -> WithTooltip<Self> {
+        WithTooltip::new(self, tooltip)
+    }
+
+    /// Sets the width of the widget, see `WithItemWidth`.
+    fn item_width(self, width: f32) -> WithItemWidth<Self> {
+        WithItemWidth::new(self, width)
+    }
+
+    /// Sets the width of all widgets inside the container, see `WithItemWidth`.
+    fn push_item_width(self, width: f32) -> WithItemWidth<Self> {
+        WithItemWidth::push(self, width)
+    }
+
+    /// Aligns the widget to the right edge of the available width, e.g. of a `Columns` cell.
+    fn align_right(self) -> Aligned<Self> {
+        Aligned::new(self, 1.0)
+    }
+
+    /// Centers the widget in the available width.
+    fn center(self) -> Aligned<Self> {
+        Aligned::new(self, 0.5)
+    }
+
+    /// Places the widget in the available width, from 0.0 at the left to 1.0 at the right edge.
+    fn align(self, alignment: f32) -> Aligned<Self> {
+        Aligned::new(self, alignment)
+    }
+}
+
+impl<W: Update> WidgetExt for W {}
+
 pub trait Get<T> {
     fn get(&self) -> T;
 }
@@ -243,6 +394,7 @@ fn show_demo_window() {
 #[derive(Clone)]
 pub struct Window {
     label: String,
+    menu_bar: Option<MenuBar>,
     widgets: Vec<Arc<dyn Update>>,
 }
 
@@ -255,14 +407,30 @@ impl Window {
 
         Window {
             label,
+            menu_bar: None,
             widgets: vec![],
         }
     }
+
+    /// Shows a menu bar at the top of the window. The shortcuts of its items only work while
+    /// the window is focused.
+    pub fn menu_bar(mut self, menu_bar: MenuBar) -> Self {
+        self.menu_bar = Some(menu_bar);
+        self
+    }
 }
 
 impl Update for Window {
     fn update(&self, gui: &Gui) -> bool {
-        unsafe { ImGui_Begin(self.label.as_ptr(), &true, 0) }
+        let flags = if self.menu_bar.is_some() {
+            WINDOW_MENU_BAR
+        } else {
+            0
+        };
+        unsafe { ImGui_Begin(self.label.as_ptr(), &true, flags) }
+        if let Some(menu_bar) = &self.menu_bar {
+            menu_bar.update(gui);
+        }
         for widget in &self.widgets {
             if widget.update(gui) {
                 widget.call_callback(gui);
@@ -340,39 +508,180 @@ impl Get<bool> for Button {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextStyle {
+    Plain,
+    Colored(Color),
+    /// wrap width in pixels, 0.0 wraps at the end of the window
+    Wrapped(f32),
+    Disabled,
+    Bullet,
+    /// text embedded in a horizontal separator
+    Separator,
+}
+
+/// Displays a string as is. The text is never interpreted as a printf format string,
+/// so values containing `%` are safe. Use `format!` or `format_args!` to build the text on the Rust side.
 #[derive(Clone)]
 pub struct Text {
     pub value: Arc<RwLock<String>>,
+    style: Arc<RwLock<TextStyle>>,
 }
 
 impl Text {
-    pub fn new(label: &str) -> Self {
+    pub fn new(text: &str) -> Self {
+        Text {
+            value: Arc::new(RwLock::new(String::from(text))),
+            style: Arc::new(RwLock::new(TextStyle::Plain)),
+        }
+    }
+
+    pub fn style(self, style: TextStyle) -> Self {
+        *self.style.blocking_write() = style;
+        self
+    }
+
+    /// Accepts a `Color` or RGBA components in the range 0.0..=1.0.
+    pub fn colored(self, color: impl Into<Color>) -> Self {
+        self.style(TextStyle::Colored(color.into()))
+    }
+
+    pub fn wrapped(self, wrap_width: f32) -> Self {
+        self.style(TextStyle::Wrapped(wrap_width))
+    }
+
+    pub fn disabled(self) -> Self {
+        self.style(TextStyle::Disabled)
+    }
+
+    pub fn bullet(self) -> Self {
+        self.style(TextStyle::Bullet)
+    }
+
+    pub fn separator(self) -> Self {
+        self.style(TextStyle::Separator)
+    }
+}
+
+impl Set<String> for Text {
+    fn set(&self, value: String) {
+        *self.value.blocking_write() = value;
+    }
+}
+
+impl Set<&str> for Text {
+    fn set(&self, value: &str) {
+        self.set(String::from(value));
+    }
+}
+
+impl Set<fmt::Arguments<'_>> for Text {
+    fn set(&self, value: fmt::Arguments<'_>) {
+        self.set(fmt::format(value));
+    }
+}
+
+impl Set<TextStyle> for Text {
+    fn set(&self, value: TextStyle) {
+        *self.style.blocking_write() = value;
+    }
+}
+
+impl Get<String> for Text {
+    fn get(&self) -> String {
+        self.value.blocking_read().clone()
+    }
+}
+
+impl Get<TextStyle> for Text {
+    fn get(&self) -> TextStyle {
+        *self.style.blocking_read()
+    }
+}
+
+impl Update for Text {
+    fn update(&self, _gui: &Gui) -> bool {
+        let text = self.value.blocking_read();
+        let (ptr, len) = (text.as_ptr(), text.len());
+        unsafe {
+            match *self.style.blocking_read() {
+                TextStyle::Plain => ImGui_Text(ptr, len),
+                TextStyle::Colored(color) => ImGui_TextColored(ptr, len, color.into()),
+                TextStyle::Wrapped(wrap_width) => ImGui_TextWrapped(ptr, len, wrap_width),
+                TextStyle::Disabled => ImGui_TextDisabled(ptr, len),
+                TextStyle::Bullet => ImGui_BulletText(ptr, len),
+                TextStyle::Separator => ImGui_SeparatorText(ptr, len),
+            }
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Displays a value next to a label, aligned like the label of an input widget.
+#[derive(Clone)]
+pub struct LabelText {
+    label: Arc<RwLock<String>>,
+    value: Arc<RwLock<String>>,
+}
+
+impl LabelText {
+    pub fn new(label: &str, text: &str) -> Self {
         let mut label = String::from_str(label).unwrap();
         if !label.ends_with('\0') {
             label.push('\0');
         };
-        Text {
-            value: Arc::new(RwLock::new(label)),
+        LabelText {
+            label: Arc::new(RwLock::new(label)),
+            value: Arc::new(RwLock::new(String::from(text))),
         }
     }
 }
 
-impl Set<String> for Text {
-    fn set(&self, mut value: String) {
-        if !value.ends_with('\0') {
-            value.push('\0');
-        }
+impl Set<String> for LabelText {
+    fn set(&self, value: String) {
         *self.value.blocking_write() = value;
     }
 }
 
-impl Get<String> for Text {
+impl Set<&str> for LabelText {
+    fn set(&self, value: &str) {
+        self.set(String::from(value));
+    }
+}
+
+impl Set<fmt::Arguments<'_>> for LabelText {
+    fn set(&self, value: fmt::Arguments<'_>) {
+        self.set(fmt::format(value));
+    }
+}
+
+impl Get<String> for LabelText {
     fn get(&self) -> String {
         self.value.blocking_read().clone()
     }
 }
 
-impl_Update!(Text, ImGui_Text(self.value.blocking_write().as_ptr()));
+impl Update for LabelText {
+    fn update(&self, _gui: &Gui) -> bool {
+        let text = self.value.blocking_read();
+        unsafe {
+            ImGui_LabelText(
+                self.label.blocking_read().as_ptr(),
+                text.as_ptr(),
+                text.len(),
+            )
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
 
 #[derive(Clone)]
 pub struct Checkbox {
@@ -420,10 +729,32 @@ impl_Update!(
 #[derive(Clone)]
 pub struct InputText {
     label: Arc<RwLock<String>>,
-    value: Arc<RwLock<String>>,
-    buffer_size: Arc<RwLock<i32>>,
+    //null terminated UTF-8, resized by Dear ImGui when the text outgrows it
+    buffer: Arc<RwLock<Vec<u8>>>,
+    //the text got set from code and has to replace the text of an active edit
+    replace_active: Arc<RwLock<bool>>,
+    hint: Arc<RwLock<Option<String>>>,
+    multiline_size: Arc<RwLock<Option<(f32, f32)>>>,
+    flags: Arc<RwLock<i32>>,
+    edited: Arc<RwLock<bool>>,
+    submitted: Arc<RwLock<bool>>,
+    //candidates of the last completion which could not be resolved to a single word
+    completions: Arc<RwLock<Vec<String>>>,
+    callback: Callback,
+    submit_callback: Callback,
+    history_callback: HistoryCallback,
+    completion_callback: CompletionCallback,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum History {
+    Previous,
+    Next,
 }
 
+type HistoryCallback = Arc<RwLock<Box<dyn Fn(History) -> Option<String> + Send + Sync>>>;
+type CompletionCallback = Arc<RwLock<Box<dyn Fn(&str) -> Vec<String> + Send + Sync>>>;
+
 impl InputText {
     pub fn new(label: &str) -> Self {
         let mut label = String::from_str(label).unwrap();
@@ -431,41 +762,318 @@ impl InputText {
             label.push('\0');
         };
 
-        let mut string = String::new();
-        for _ in 0..255 {
-            string.push('\0');
-        }
         InputText {
             label: Arc::new(RwLock::new(label)),
-            value: Arc::new(RwLock::new(string)),
-            buffer_size: Arc::new(RwLock::new(255)),
+            buffer: Arc::new(RwLock::new(vec![0])),
+            replace_active: Arc::new(RwLock::new(false)),
+            hint: Arc::new(RwLock::new(None)),
+            multiline_size: Arc::new(RwLock::new(None)),
+            flags: Arc::new(RwLock::new(0)),
+            edited: Arc::new(RwLock::new(false)),
+            submitted: Arc::new(RwLock::new(false)),
+            completions: Arc::new(RwLock::new(vec![])),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+            submit_callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+            history_callback: Arc::new(RwLock::new(Box::new(|_: History| None))),
+            completion_callback: Arc::new(RwLock::new(Box::new(|_: &str| vec![]))),
         }
     }
+
+    fn flag(self, flag: i32) -> Self {
+        *self.flags.blocking_write() |= flag;
+        self
+    }
+
+    /// A size of 0.0 uses the default width or a height of eight lines.
+    pub fn multiline(self, width: f32, height: f32) -> Self {
+        *self.multiline_size.blocking_write() = Some((width, height));
+        self
+    }
+
+    /// Greyed out text shown while the input is empty. Not supported in multiline mode.
+    pub fn hint(self, hint: &str) -> Self {
+        let mut hint = String::from_str(hint).unwrap();
+        if !hint.ends_with('\0') {
+            hint.push('\0');
+        };
+        *self.hint.blocking_write() = Some(hint);
+        self
+    }
+
+    pub fn password(self) -> Self {
+        self.flag(INPUT_TEXT_PASSWORD)
+    }
+
+    pub fn read_only(self) -> Self {
+        self.flag(INPUT_TEXT_READ_ONLY)
+    }
+
+    /// Allows only `0123456789.+-*/`.
+    pub fn chars_decimal(self) -> Self {
+        self.flag(INPUT_TEXT_CHARS_DECIMAL)
+    }
+
+    /// Allows only `0123456789ABCDEFabcdef`.
+    pub fn chars_hexadecimal(self) -> Self {
+        self.flag(INPUT_TEXT_CHARS_HEXADECIMAL)
+    }
+
+    pub fn chars_uppercase(self) -> Self {
+        self.flag(INPUT_TEXT_CHARS_UPPERCASE)
+    }
+
+    pub fn chars_no_blank(self) -> Self {
+        self.flag(INPUT_TEXT_CHARS_NO_BLANK)
+    }
+
+    pub fn auto_select_all(self) -> Self {
+        self.flag(INPUT_TEXT_AUTO_SELECT_ALL)
+    }
+
+    /// Pressing enter submits the text. In multiline mode ctrl+enter inserts a new line instead.
+    pub fn enter_returns_true(self) -> Self {
+        self.flag(INPUT_TEXT_ENTER_RETURNS_TRUE)
+    }
+
+    pub fn set_submit_callback<T: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: T) -> Self {
+        self.submit_callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    /// Called when the up or down arrow is pressed. Returning a string replaces the text.
+    ///
+    /// The callback runs while the widget is rendered, so it must not access this InputText.
+    pub fn set_history_callback<T>(mut self, callback: T) -> Self
+    where
+        T: 'static + Send + Sync + Fn(History) -> Option<String>,
+    {
+        self.history_callback = Arc::new(RwLock::new(Box::new(callback)));
+        self.flag(INPUT_TEXT_CALLBACK_HISTORY)
+    }
+
+    /// Called with the word in front of the cursor when tab is pressed. A single candidate replaces the word,
+    /// multiple candidates complete their common prefix and are available through `Get<Vec<String>>`.
+    ///
+    /// The callback runs while the widget is rendered, so it must not access this InputText.
+    pub fn set_completion_callback<T>(mut self, callback: T) -> Self
+    where
+        T: 'static + Send + Sync + Fn(&str) -> Vec<String>,
+    {
+        self.completion_callback = Arc::new(RwLock::new(Box::new(callback)));
+        self.flag(INPUT_TEXT_CALLBACK_COMPLETION)
+    }
 }
 
 impl Get<String> for InputText {
     fn get(&self) -> String {
-        let null_terminator_position = self.value.blocking_read().find('\0').unwrap();
+        let buffer = self.buffer.blocking_read();
+        let len = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+        String::from_utf8_lossy(&buffer[..len]).into_owned()
+    }
+}
 
-        // if a truncation of a non ascii character occurred the string has a invalid memory layout past the intended end.
-        String::from(&self.value.blocking_read()[..null_terminator_position])
+/// Whether the text got submitted with enter during the last frame, see `InputText::enter_returns_true`.
+impl Get<bool> for InputText {
+    fn get(&self) -> bool {
+        *self.submitted.blocking_read()
     }
 }
 
-impl_Update!(
-    InputText,
-    ImGui_InputText(
-        self.label.blocking_write().as_ptr(),
-        self.value.blocking_write().as_ptr(),
-        *self.buffer_size.blocking_write(),
-        0
-    )
-);
+/// The candidates of the last ambiguous tab completion, see `InputText::set_completion_callback`.
+impl Get<Vec<String>> for InputText {
+    fn get(&self) -> Vec<String> {
+        self.completions.blocking_read().clone()
+    }
+}
+
+impl Set<String> for InputText {
+    fn set(&self, value: String) {
+        let mut buffer = value.into_bytes();
+        if let Some(len) = buffer.iter().position(|c| *c == 0) {
+            buffer.truncate(len);
+        }
+        buffer.push(0);
+        *self.buffer.blocking_write() = buffer;
+        *self.replace_active.blocking_write() = true;
+    }
+}
+
+impl Set<&str> for InputText {
+    fn set(&self, value: &str) {
+        self.set(String::from(value));
+    }
+}
+
+//state of an InputText while Dear ImGui renders it
+struct InputTextFrame<'a> {
+    input: &'a InputText,
+    buffer: &'a mut Vec<u8>,
+    //keeps the text of an edit alive until Dear ImGui copied it
+    replacement: String,
+}
+
+extern "C" fn resize_input_text(frame: *mut c_void, size: usize) -> *mut u8 {
+    let frame = unsafe { &mut *(frame as *mut InputTextFrame) };
+    frame.buffer.resize(size, 0);
+    frame.buffer.as_mut_ptr()
+}
+
+extern "C" fn edit_input_text(
+    frame: *mut c_void,
+    event: i32,
+    text: *const u8,
+    len: usize,
+    cursor: usize,
+    edit: *mut InputTextEdit,
+) -> bool {
+    let frame = unsafe { &mut *(frame as *mut InputTextFrame) };
+    let text = match std::str::from_utf8(unsafe { std::slice::from_raw_parts(text, len) }) {
+        Ok(text) => text,
+        Err(_) => return false,
+    };
+
+    let (start, end) = match event {
+        INPUT_TEXT_HISTORY_PREVIOUS | INPUT_TEXT_HISTORY_NEXT => {
+            let direction = if event == INPUT_TEXT_HISTORY_PREVIOUS {
+                History::Previous
+            } else {
+                History::Next
+            };
+            match (frame.input.history_callback.blocking_read())(direction) {
+                Some(entry) => frame.replacement = entry,
+                None => return false,
+            }
+            (0, text.len())
+        }
+        INPUT_TEXT_COMPLETION => {
+            let cursor = cursor.min(text.len());
+            let start = completion_start(text, cursor);
+            let word = &text[start..cursor];
+            let candidates = (frame.input.completion_callback.blocking_read())(word);
+            let replacement = completion(word, &candidates);
+            if candidates.len() > 1 {
+                *frame.input.completions.blocking_write() = candidates;
+            }
+            match replacement {
+                Some(replacement) => frame.replacement = replacement,
+                None => return false,
+            }
+            (start, cursor)
+        }
+        _ => return false,
+    };
+
+    unsafe {
+        *edit = InputTextEdit {
+            start,
+            end,
+            text: frame.replacement.as_ptr(),
+            len: frame.replacement.len(),
+        };
+    }
+    true
+}
+
+//the start of the word before the cursor
+fn completion_start(text: &str, cursor: usize) -> usize {
+    text[..cursor]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace() || *c == ',' || *c == ';')
+        .map_or(0, |(i, c)| i + c.len_utf8())
+}
+
+//the text replacing the word: a single candidate followed by a space, or the common prefix of
+//several candidates if it is longer than the word, so differently cased candidates keep the word
+fn completion(word: &str, candidates: &[String]) -> Option<String> {
+    match candidates {
+        [] => None,
+        [candidate] => Some(format!("{} ", candidate)),
+        [first, rest @ ..] => {
+            let mut prefix = first.as_str();
+            for candidate in rest {
+                let len = prefix
+                    .chars()
+                    .zip(candidate.chars())
+                    .take_while(|(a, b)| a == b)
+                    .map(|(a, _)| a.len_utf8())
+                    .sum();
+                prefix = &prefix[..len];
+            }
+            (prefix.len() > word.len()).then(|| String::from(prefix))
+        }
+    }
+}
+
+impl Update for InputText {
+    fn update(&self, _gui: &Gui) -> bool {
+        let replace_active = std::mem::take(&mut *self.replace_active.blocking_write());
+        self.completions.blocking_write().clear();
+        let mut buffer = self.buffer.blocking_write();
+        let (buffer_ptr, buffer_size) = (buffer.as_mut_ptr(), buffer.len());
+        let mut frame = InputTextFrame {
+            input: self,
+            buffer: &mut buffer,
+            replacement: String::new(),
+        };
+        let mut handler = InputTextHandler {
+            data: &mut frame as *mut InputTextFrame as *mut c_void,
+            resize: resize_input_text,
+            edit: edit_input_text,
+        };
+        let hint = self.hint.blocking_read();
+        let (multiline, width, height) = match *self.multiline_size.blocking_read() {
+            Some((width, height)) => (true, width, height),
+            None => (false, 0.0, 0.0),
+        };
+        let events = unsafe {
+            ImGui_InputText(
+                self.label.blocking_read().as_ptr(),
+                hint.as_ref().map_or(std::ptr::null(), |hint| hint.as_ptr()),
+                buffer_ptr,
+                buffer_size,
+                *self.flags.blocking_read(),
+                multiline,
+                width,
+                height,
+                replace_active,
+                &mut handler,
+            )
+        };
+        let edited = events & INPUT_TEXT_EDITED != 0;
+        let submitted = events & INPUT_TEXT_SUBMITTED != 0;
+        *self.edited.blocking_write() = edited;
+        *self.submitted.blocking_write() = submitted;
+        edited || submitted
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        if *self.edited.blocking_read() {
+            (self.callback.blocking_read())(gui);
+        }
+        if *self.submitted.blocking_read() {
+            (self.submit_callback.blocking_read())(gui);
+        }
+    }
+
+    fn set_callback<T: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: T) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
 
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Edits a color. Shows a row of inputs with a preview button by default, `picker` shows a color
+/// wheel instead.
 #[derive(Clone)]
 pub struct InputColor {
     label: Arc<RwLock<String>>,
     value: Arc<RwLock<ImGui_Vec4>>,
+    flags: Arc<RwLock<i32>>,
+    callback: Callback,
 }
 
 impl InputColor {
@@ -484,8 +1092,50 @@ impl InputColor {
         InputColor {
             label: Arc::new(RwLock::new(label)),
             value: Arc::new(RwLock::new(value)),
+            flags: Arc::new(RwLock::new(0)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
         }
     }
+
+    pub fn color(self, color: Color) -> Self {
+        self.set(color);
+        self
+    }
+
+    /// Edits the alpha component as well (ColorEdit4/ColorPicker4).
+    pub fn alpha(self) -> Self {
+        self.flag(COLOR_ALPHA)
+    }
+
+    /// Shows a color picker instead of the input fields.
+    pub fn picker(self) -> Self {
+        self.flag(COLOR_PICKER)
+    }
+
+    /// Allows components outside of 0.0 to 1.0.
+    pub fn hdr(self) -> Self {
+        self.flag(COLOR_HDR)
+    }
+
+    /// Only shows the preview button.
+    pub fn no_inputs(self) -> Self {
+        self.flag(COLOR_NO_INPUTS)
+    }
+
+    pub fn display_hex(self) -> Self {
+        self.flag(COLOR_DISPLAY_HEX)
+    }
+
+    fn flag(self, flag: i32) -> Self {
+        *self.flags.blocking_write() |= flag;
+        self
+    }
+}
+
+impl Get<Color> for InputColor {
+    fn get(&self) -> Color {
+        Color::from(*self.value.blocking_read())
+    }
 }
 
 impl Get<Vec<f32>> for InputColor {
@@ -495,13 +1145,126 @@ impl Get<Vec<f32>> for InputColor {
     }
 }
 
-impl_Update!(
-    InputColor,
-    ImGui_ColorEdit3(
-        self.label.blocking_write().as_ptr(),
-        &self.value.blocking_write()
-    )
-);
+impl Set<Color> for InputColor {
+    fn set(&self, value: Color) {
+        *self.value.blocking_write() = value.into();
+    }
+}
+
+impl Update for InputColor {
+    fn update(&self, _gui: &Gui) -> bool {
+        unsafe {
+            ImGui_ColorEdit(
+                self.label.blocking_read().as_ptr(),
+                &mut self.value.blocking_write(),
+                *self.flags.blocking_read(),
+            )
+        }
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<T: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: T) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A button filled with a color. The label is only used as id and shown in the tooltip.
+#[derive(Clone)]
+pub struct ColorButton {
+    label: Arc<RwLock<String>>,
+    color: Arc<RwLock<ImGui_Vec4>>,
+    size: Arc<RwLock<ImGui_Vec2>>,
+    flags: Arc<RwLock<i32>>,
+    value: Arc<RwLock<bool>>,
+    callback: Callback,
+}
+
+impl ColorButton {
+    pub fn new(label: &str, color: Color) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        ColorButton {
+            label: Arc::new(RwLock::new(label)),
+            color: Arc::new(RwLock::new(color.into())),
+            size: Arc::new(RwLock::new(ImGui_Vec2::default())),
+            flags: Arc::new(RwLock::new(COLOR_ALPHA)),
+            value: Arc::new(RwLock::new(false)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    /// A size of zero uses the frame height.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        *self.size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
+
+    /// Ignores the alpha component.
+    pub fn no_alpha(self) -> Self {
+        *self.flags.blocking_write() &= !COLOR_ALPHA;
+        self
+    }
+}
+
+impl Get<bool> for ColorButton {
+    fn get(&self) -> bool {
+        *self.value.blocking_read()
+    }
+}
+
+impl Get<Color> for ColorButton {
+    fn get(&self) -> Color {
+        Color::from(*self.color.blocking_read())
+    }
+}
+
+impl Set<Color> for ColorButton {
+    fn set(&self, value: Color) {
+        *self.color.blocking_write() = value.into();
+    }
+}
+
+impl Update for ColorButton {
+    fn update(&self, _gui: &Gui) -> bool {
+        let clicked = unsafe {
+            ImGui_ColorButton(
+                self.label.blocking_read().as_ptr(),
+                *self.color.blocking_read(),
+                *self.flags.blocking_read(),
+                *self.size.blocking_read(),
+            )
+        };
+        *self.value.blocking_write() = clicked;
+        clicked
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<T: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: T) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
 
 #[derive(Clone)]
 pub struct SameLine {
@@ -533,12 +1296,18 @@ impl_Update!(
     )
 );
 
+/// The range and the format can be changed at runtime, the flags and the vertical size only
+/// when building the slider.
 #[derive(Clone)]
 pub struct SliderInt {
     label: Arc<RwLock<String>>,
     value: Arc<RwLock<i32>>,
     min: Arc<RwLock<i32>>,
     max: Arc<RwLock<i32>>,
+    format: Arc<RwLock<String>>,
+    flags: Arc<RwLock<i32>>,
+    //a size of zero draws a horizontal slider
+    vertical_size: Arc<RwLock<ImGui_Vec2>>,
     callback: Callback,
 }
 
@@ -554,9 +1323,61 @@ impl SliderInt {
             value: Arc::new(RwLock::new(0)),
             min: Arc::new(RwLock::new(0)),
             max: Arc::new(RwLock::new(100)),
+            format: Arc::new(RwLock::new(String::from("%d\0"))),
+            flags: Arc::new(RwLock::new(0)),
+            vertical_size: Arc::new(RwLock::new(ImGui_Vec2::default())),
             callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
         }
     }
+
+    pub fn range(self, min: i32, max: i32) -> Self {
+        self.set_range(min, max);
+        self
+    }
+
+    pub fn set_range(&self, min: i32, max: i32) {
+        *self.min.blocking_write() = min;
+        *self.max.blocking_write() = max;
+    }
+
+    /// printf style format of the value, e.g. `"%d ms"`.
+    pub fn format(self, format: &str) -> Self {
+        self.set_format(format);
+        self
+    }
+
+    pub fn set_format(&self, format: &str) {
+        let mut format = String::from_str(format).unwrap();
+        if !format.ends_with('\0') {
+            format.push('\0');
+        };
+        *self.format.blocking_write() = format;
+    }
+
+    pub fn logarithmic(self) -> Self {
+        *self.flags.blocking_write() |= SLIDER_LOGARITHMIC;
+        self
+    }
+
+    /// Disables entering a value with ctrl+click.
+    pub fn no_input(self) -> Self {
+        *self.flags.blocking_write() |= SLIDER_NO_INPUT;
+        self
+    }
+
+    /// Clamps values entered with ctrl+click to the range as well.
+    pub fn always_clamp(self) -> Self {
+        *self.flags.blocking_write() |= SLIDER_ALWAYS_CLAMP;
+        self
+    }
+
+    pub fn vertical(self, width: f32, height: f32) -> Self {
+        *self.vertical_size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
 }
 
 impl Get<i32> for SliderInt {
@@ -577,18 +1398,27 @@ impl_Update!(
         self.label.blocking_write().as_ptr(),
         &self.value.blocking_write(),
         *self.min.blocking_read(),
-        *self.max.blocking_read()
+        *self.max.blocking_read(),
+        self.format.blocking_read().as_ptr(),
+        *self.flags.blocking_read(),
+        *self.vertical_size.blocking_read()
     ),
     callback,
     value
 );
 
+/// The range and the format can be changed at runtime, the flags and the vertical size only
+/// when building the slider.
 #[derive(Clone)]
 pub struct SliderFloat {
     label: Arc<RwLock<String>>,
     value: Arc<RwLock<f32>>,
     min: Arc<RwLock<f32>>,
     max: Arc<RwLock<f32>>,
+    format: Arc<RwLock<String>>,
+    flags: Arc<RwLock<i32>>,
+    //a size of zero draws a horizontal slider
+    vertical_size: Arc<RwLock<ImGui_Vec2>>,
     callback: Callback,
 }
 
@@ -604,9 +1434,61 @@ impl SliderFloat {
             value: Arc::new(RwLock::new(0.0)),
             min: Arc::new(RwLock::new(0.0)),
             max: Arc::new(RwLock::new(100.0)),
+            format: Arc::new(RwLock::new(String::from("%.3f\0"))),
+            flags: Arc::new(RwLock::new(0)),
+            vertical_size: Arc::new(RwLock::new(ImGui_Vec2::default())),
             callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
         }
     }
+
+    pub fn range(self, min: f32, max: f32) -> Self {
+        self.set_range(min, max);
+        self
+    }
+
+    pub fn set_range(&self, min: f32, max: f32) {
+        *self.min.blocking_write() = min;
+        *self.max.blocking_write() = max;
+    }
+
+    /// printf style format of the value, e.g. `"%.1f ms"`.
+    pub fn format(self, format: &str) -> Self {
+        self.set_format(format);
+        self
+    }
+
+    pub fn set_format(&self, format: &str) {
+        let mut format = String::from_str(format).unwrap();
+        if !format.ends_with('\0') {
+            format.push('\0');
+        };
+        *self.format.blocking_write() = format;
+    }
+
+    pub fn logarithmic(self) -> Self {
+        *self.flags.blocking_write() |= SLIDER_LOGARITHMIC;
+        self
+    }
+
+    /// Disables entering a value with ctrl+click.
+    pub fn no_input(self) -> Self {
+        *self.flags.blocking_write() |= SLIDER_NO_INPUT;
+        self
+    }
+
+    /// Clamps values entered with ctrl+click to the range as well.
+    pub fn always_clamp(self) -> Self {
+        *self.flags.blocking_write() |= SLIDER_ALWAYS_CLAMP;
+        self
+    }
+
+    pub fn vertical(self, width: f32, height: f32) -> Self {
+        *self.vertical_size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
 }
 
 impl Get<f32> for SliderFloat {
@@ -627,12 +1509,523 @@ impl_Update!(
         self.label.blocking_write().as_ptr(),
         &self.value.blocking_write(),
         *self.min.blocking_read(),
-        *self.max.blocking_read()
+        *self.max.blocking_read(),
+        self.format.blocking_read().as_ptr(),
+        *self.flags.blocking_read(),
+        *self.vertical_size.blocking_read()
     ),
     callback,
     value
 );
 
+/// A slider for an angle stored in radians and displayed in degrees.
+/// The flags can only be set when building the slider.
+#[derive(Clone)]
+pub struct SliderAngle {
+    label: Arc<RwLock<String>>,
+    value: Arc<RwLock<f32>>,
+    min_degrees: Arc<RwLock<f32>>,
+    max_degrees: Arc<RwLock<f32>>,
+    format: Arc<RwLock<String>>,
+    flags: Arc<RwLock<i32>>,
+    callback: Callback,
+}
+
+impl SliderAngle {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        SliderAngle {
+            label: Arc::new(RwLock::new(label)),
+            value: Arc::new(RwLock::new(0.0)),
+            min_degrees: Arc::new(RwLock::new(-360.0)),
+            max_degrees: Arc::new(RwLock::new(360.0)),
+            format: Arc::new(RwLock::new(String::from("%.0f deg\0"))),
+            flags: Arc::new(RwLock::new(0)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    pub fn range(self, min_degrees: f32, max_degrees: f32) -> Self {
+        self.set_range(min_degrees, max_degrees);
+        self
+    }
+
+    pub fn set_range(&self, min_degrees: f32, max_degrees: f32) {
+        *self.min_degrees.blocking_write() = min_degrees;
+        *self.max_degrees.blocking_write() = max_degrees;
+    }
+
+    /// printf style format of the value in degrees.
+    pub fn format(self, format: &str) -> Self {
+        self.set_format(format);
+        self
+    }
+
+    pub fn set_format(&self, format: &str) {
+        let mut format = String::from_str(format).unwrap();
+        if !format.ends_with('\0') {
+            format.push('\0');
+        };
+        *self.format.blocking_write() = format;
+    }
+
+    /// Disables entering a value with ctrl+click.
+    pub fn no_input(self) -> Self {
+        *self.flags.blocking_write() |= SLIDER_NO_INPUT;
+        self
+    }
+
+    /// Clamps values entered with ctrl+click to the range as well.
+    pub fn always_clamp(self) -> Self {
+        *self.flags.blocking_write() |= SLIDER_ALWAYS_CLAMP;
+        self
+    }
+}
+
+/// The angle in radians.
+impl Get<f32> for SliderAngle {
+    fn get(&self) -> f32 {
+        *self.value.blocking_read()
+    }
+}
+
+/// The angle in radians.
+impl Set<f32> for SliderAngle {
+    fn set(&self, value: f32) {
+        *self.value.blocking_write() = value;
+    }
+}
+
+impl_Update!(
+    SliderAngle,
+    ImGui_SliderAngle(
+        self.label.blocking_read().as_ptr(),
+        &self.value.blocking_write(),
+        *self.min_degrees.blocking_read(),
+        *self.max_degrees.blocking_read(),
+        self.format.blocking_read().as_ptr(),
+        *self.flags.blocking_read()
+    ),
+    callback,
+    value
+);
+
+/// Numeric types usable with the scalar input widgets.
+pub trait Scalar: Copy + Default + PartialOrd + Send + Sync + 'static {
+    //ImGuiDataType of the type
+    const DATA_TYPE: i32;
+    //null terminated printf format
+    const FORMAT: &'static str;
+    const STEP: Option<(Self, Self)>;
+}
+
+impl Scalar for i32 {
+    const DATA_TYPE: i32 = IMGUI_DATA_TYPE_S32;
+    const FORMAT: &'static str = "%d\0";
+    const STEP: Option<(Self, Self)> = Some((1, 100));
+}
+
+impl Scalar for f32 {
+    const DATA_TYPE: i32 = IMGUI_DATA_TYPE_FLOAT;
+    const FORMAT: &'static str = "%.3f\0";
+    const STEP: Option<(Self, Self)> = None;
+}
+
+impl Scalar for f64 {
+    const DATA_TYPE: i32 = IMGUI_DATA_TYPE_DOUBLE;
+    const FORMAT: &'static str = "%.6f\0";
+    const STEP: Option<(Self, Self)> = None;
+}
+
+fn clamp_scalar<T: Scalar>(value: T, range: Option<(T, T)>) -> T {
+    match range {
+        Some((min, _)) if value < min => min,
+        Some((_, max)) if value > max => max,
+        _ => value,
+    }
+}
+
+/// A text field for `N` numbers of type `T`, see the aliases `InputInt`, `InputFloat3`, ...
+#[derive(Clone)]
+pub struct InputScalar<T: Scalar, const N: usize> {
+    label: Arc<RwLock<String>>,
+    value: Arc<RwLock<[T; N]>>,
+    step: Arc<RwLock<Option<(T, T)>>>,
+    format: Arc<RwLock<String>>,
+    clamp: Arc<RwLock<Option<(T, T)>>>,
+    callback: Callback,
+}
+
+pub type InputInt = InputScalar<i32, 1>;
+pub type InputInt2 = InputScalar<i32, 2>;
+pub type InputInt3 = InputScalar<i32, 3>;
+pub type InputInt4 = InputScalar<i32, 4>;
+pub type InputFloat = InputScalar<f32, 1>;
+pub type InputFloat2 = InputScalar<f32, 2>;
+pub type InputFloat3 = InputScalar<f32, 3>;
+pub type InputFloat4 = InputScalar<f32, 4>;
+pub type InputDouble = InputScalar<f64, 1>;
+pub type InputDouble2 = InputScalar<f64, 2>;
+pub type InputDouble3 = InputScalar<f64, 3>;
+pub type InputDouble4 = InputScalar<f64, 4>;
+
+impl<T: Scalar, const N: usize> InputScalar<T, N> {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        InputScalar {
+            label: Arc::new(RwLock::new(label)),
+            value: Arc::new(RwLock::new([T::default(); N])),
+            step: Arc::new(RwLock::new(if N == 1 { T::STEP } else { None })),
+            format: Arc::new(RwLock::new(String::from(T::FORMAT))),
+            clamp: Arc::new(RwLock::new(None)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    /// Shows -/+ buttons, step_fast is used while ctrl is held. A step of zero removes the buttons.
+    pub fn step(self, step: T, step_fast: T) -> Self {
+        *self.step.blocking_write() = if step == T::default() {
+            None
+        } else {
+            Some((step, step_fast))
+        };
+        self
+    }
+
+    /// printf style format of a single value, e.g. `"%d px"` for integers or `"%.2f ms"` for floats.
+    pub fn format(self, format: &str) -> Self {
+        let mut format = String::from_str(format).unwrap();
+        if !format.ends_with('\0') {
+            format.push('\0');
+        };
+        *self.format.blocking_write() = format;
+        self
+    }
+
+    /// Keeps entered and set values within min..=max, the current value gets clamped as well.
+    pub fn clamp(self, min: T, max: T) -> Self {
+        *self.clamp.blocking_write() = Some((min, max));
+        let value = *self.value.blocking_read();
+        self.set(value);
+        self
+    }
+}
+
+impl<T: Scalar> Get<T> for InputScalar<T, 1> {
+    fn get(&self) -> T {
+        self.value.blocking_read()[0]
+    }
+}
+
+impl<T: Scalar, const N: usize> Get<[T; N]> for InputScalar<T, N> {
+    fn get(&self) -> [T; N] {
+        *self.value.blocking_read()
+    }
+}
+
+impl<T: Scalar> Set<T> for InputScalar<T, 1> {
+    fn set(&self, value: T) {
+        self.set([value]);
+    }
+}
+
+impl<T: Scalar, const N: usize> Set<[T; N]> for InputScalar<T, N> {
+    fn set(&self, value: [T; N]) {
+        let clamp = *self.clamp.blocking_read();
+        *self.value.blocking_write() = value.map(|v| clamp_scalar(v, clamp));
+    }
+}
+
+impl<T: Scalar, const N: usize> Update for InputScalar<T, N> {
+    fn update(&self, _gui: &Gui) -> bool {
+        let mut value = self.value.blocking_write();
+        let step = *self.step.blocking_read();
+        let (step_ptr, step_fast_ptr) = match &step {
+            Some((step, step_fast)) => (
+                step as *const T as *const c_void,
+                step_fast as *const T as *const c_void,
+            ),
+            None => (std::ptr::null(), std::ptr::null()),
+        };
+        let changed = unsafe {
+            ImGui_InputScalarN(
+                self.label.blocking_read().as_ptr(),
+                T::DATA_TYPE,
+                value.as_mut_ptr() as *mut c_void,
+                N as i32,
+                step_ptr,
+                step_fast_ptr,
+                self.format.blocking_read().as_ptr(),
+            )
+        };
+        if changed {
+            let clamp = *self.clamp.blocking_read();
+            for v in value.iter_mut() {
+                *v = clamp_scalar(*v, clamp);
+            }
+        }
+        changed
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: F) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Changes `N` numbers of type `T` by dragging the mouse, see the aliases `DragInt`, `DragFloat3`, ...
+/// Double-click or ctrl+click to enter a value.
+#[derive(Clone)]
+pub struct DragScalar<T: Scalar, const N: usize> {
+    label: Arc<RwLock<String>>,
+    value: Arc<RwLock<[T; N]>>,
+    speed: Arc<RwLock<f32>>,
+    range: Arc<RwLock<Option<(T, T)>>>,
+    format: Arc<RwLock<String>>,
+    callback: Callback,
+}
+
+pub type DragInt = DragScalar<i32, 1>;
+pub type DragInt2 = DragScalar<i32, 2>;
+pub type DragInt3 = DragScalar<i32, 3>;
+pub type DragInt4 = DragScalar<i32, 4>;
+pub type DragFloat = DragScalar<f32, 1>;
+pub type DragFloat2 = DragScalar<f32, 2>;
+pub type DragFloat3 = DragScalar<f32, 3>;
+pub type DragFloat4 = DragScalar<f32, 4>;
+pub type DragDouble = DragScalar<f64, 1>;
+pub type DragDouble2 = DragScalar<f64, 2>;
+pub type DragDouble3 = DragScalar<f64, 3>;
+pub type DragDouble4 = DragScalar<f64, 4>;
+
+impl<T: Scalar, const N: usize> DragScalar<T, N> {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        DragScalar {
+            label: Arc::new(RwLock::new(label)),
+            value: Arc::new(RwLock::new([T::default(); N])),
+            speed: Arc::new(RwLock::new(1.0)),
+            range: Arc::new(RwLock::new(None)),
+            format: Arc::new(RwLock::new(String::from(T::FORMAT))),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    /// Change of the value per pixel of mouse movement.
+    pub fn speed(self, speed: f32) -> Self {
+        *self.speed.blocking_write() = speed;
+        self
+    }
+
+    /// Limits dragging to min..=max, the value is unbounded by default.
+    pub fn range(self, min: T, max: T) -> Self {
+        *self.range.blocking_write() = Some((min, max));
+        self
+    }
+
+    /// printf style format of a single value, e.g. `"%d px"` for integers or `"%.2f ms"` for floats.
+    pub fn format(self, format: &str) -> Self {
+        let mut format = String::from_str(format).unwrap();
+        if !format.ends_with('\0') {
+            format.push('\0');
+        };
+        *self.format.blocking_write() = format;
+        self
+    }
+}
+
+impl<T: Scalar> Get<T> for DragScalar<T, 1> {
+    fn get(&self) -> T {
+        self.value.blocking_read()[0]
+    }
+}
+
+impl<T: Scalar, const N: usize> Get<[T; N]> for DragScalar<T, N> {
+    fn get(&self) -> [T; N] {
+        *self.value.blocking_read()
+    }
+}
+
+impl<T: Scalar> Set<T> for DragScalar<T, 1> {
+    fn set(&self, value: T) {
+        self.set([value]);
+    }
+}
+
+impl<T: Scalar, const N: usize> Set<[T; N]> for DragScalar<T, N> {
+    fn set(&self, value: [T; N]) {
+        *self.value.blocking_write() = value;
+    }
+}
+
+impl<T: Scalar, const N: usize> Update for DragScalar<T, N> {
+    fn update(&self, _gui: &Gui) -> bool {
+        let range = *self.range.blocking_read();
+        let (min_ptr, max_ptr) = match &range {
+            Some((min, max)) => (
+                min as *const T as *const c_void,
+                max as *const T as *const c_void,
+            ),
+            None => (std::ptr::null(), std::ptr::null()),
+        };
+        unsafe {
+            ImGui_DragScalarN(
+                self.label.blocking_read().as_ptr(),
+                T::DATA_TYPE,
+                self.value.blocking_write().as_mut_ptr() as *mut c_void,
+                N as i32,
+                *self.speed.blocking_read(),
+                min_ptr,
+                max_ptr,
+                self.format.blocking_read().as_ptr(),
+            )
+        }
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: F) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Scalars supported by `DragRange`.
+pub trait RangeScalar: Scalar {}
+
+impl RangeScalar for i32 {}
+
+impl RangeScalar for f32 {}
+
+/// Two draggable values forming a range `(min, max)` where min can't be dragged above max.
+#[derive(Clone)]
+pub struct DragRange<T: RangeScalar> {
+    label: Arc<RwLock<String>>,
+    value: Arc<RwLock<[T; 2]>>,
+    speed: Arc<RwLock<f32>>,
+    range: Arc<RwLock<Option<(T, T)>>>,
+    format: Arc<RwLock<String>>,
+    callback: Callback,
+}
+
+pub type DragIntRange2 = DragRange<i32>;
+pub type DragFloatRange2 = DragRange<f32>;
+
+impl<T: RangeScalar> DragRange<T> {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        DragRange {
+            label: Arc::new(RwLock::new(label)),
+            value: Arc::new(RwLock::new([T::default(); 2])),
+            speed: Arc::new(RwLock::new(1.0)),
+            range: Arc::new(RwLock::new(None)),
+            format: Arc::new(RwLock::new(String::from(T::FORMAT))),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    /// Change of the value per pixel of mouse movement.
+    pub fn speed(self, speed: f32) -> Self {
+        *self.speed.blocking_write() = speed;
+        self
+    }
+
+    /// Limits both values to min..=max, the values are unbounded by default.
+    pub fn range(self, min: T, max: T) -> Self {
+        *self.range.blocking_write() = Some((min, max));
+        self
+    }
+
+    /// printf style format of a single value, e.g. `"%d px"` for integers or `"%.2f ms"` for floats.
+    pub fn format(self, format: &str) -> Self {
+        let mut format = String::from_str(format).unwrap();
+        if !format.ends_with('\0') {
+            format.push('\0');
+        };
+        *self.format.blocking_write() = format;
+        self
+    }
+}
+
+impl<T: RangeScalar> Get<(T, T)> for DragRange<T> {
+    fn get(&self) -> (T, T) {
+        let [min, max] = *self.value.blocking_read();
+        (min, max)
+    }
+}
+
+impl<T: RangeScalar> Set<(T, T)> for DragRange<T> {
+    fn set(&self, value: (T, T)) {
+        *self.value.blocking_write() = [value.0, value.1];
+    }
+}
+
+impl<T: RangeScalar> Update for DragRange<T> {
+    fn update(&self, _gui: &Gui) -> bool {
+        //Dear ImGui treats min == max as unbounded
+        let (min, max) = self
+            .range
+            .blocking_read()
+            .unwrap_or((T::default(), T::default()));
+        let mut value = self.value.blocking_write();
+        let format = self.format.blocking_read();
+        unsafe {
+            ImGui_DragRange2(
+                self.label.blocking_read().as_ptr(),
+                T::DATA_TYPE,
+                value.as_mut_ptr() as *mut c_void,
+                *self.speed.blocking_read(),
+                &min as *const T as *const c_void,
+                &max as *const T as *const c_void,
+                format.as_ptr(),
+            )
+        }
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: F) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct TreeNode {
     flags: Arc<RwLock<i32>>,
@@ -688,3 +2081,52 @@ impl Update for TreeNode {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(candidates: &[&str]) -> Vec<String> {
+        candidates.iter().map(|c| String::from(*c)).collect()
+    }
+
+    #[test]
+    fn completion_word() {
+        assert_eq!(completion_start("", 0), 0);
+        assert_eq!(completion_start("hel", 3), 0);
+        assert_eq!(completion_start("print he", 8), 6);
+        assert_eq!(completion_start("a,b;cd", 6), 4);
+        assert_eq!(completion_start("ä öl", 5), 3);
+        //only the text before the cursor counts
+        assert_eq!(completion_start("ab cd", 2), 0);
+    }
+
+    #[test]
+    fn single_candidate_completes_the_word() {
+        assert_eq!(
+            completion("he", &candidates(&["help"])),
+            Some(String::from("help "))
+        );
+        assert_eq!(completion("he", &[]), None);
+    }
+
+    #[test]
+    fn several_candidates_complete_the_common_prefix() {
+        assert_eq!(
+            completion("h", &candidates(&["history", "hist", "histogram"])),
+            Some(String::from("hist"))
+        );
+        assert_eq!(
+            completion("ö", &candidates(&["öffnen", "öffentlich"])),
+            Some(String::from("öff"))
+        );
+    }
+
+    #[test]
+    fn short_prefix_keeps_the_word() {
+        assert_eq!(completion("he", &candidates(&["help", "hex"])), None);
+        //case-insensitive candidates share no prefix
+        assert_eq!(completion("cl", &candidates(&["CLEAR", "Clone"])), None);
+        assert_eq!(completion("x", &candidates(&["a", "b"])), None);
+    }
+}
@@ -0,0 +1,492 @@
+use crate::backend::*;
+use crate::{Get, Gui, Set, Update};
+use std::{any::Any, sync::Arc};
+use tokio::sync::RwLock;
+
+//the style bits have to match the MarkdownStyle enum in gui_lib.cpp
+const BOLD: i32 = 1 << 0;
+const ITALIC: i32 = 1 << 1;
+const CODE: i32 = 1 << 2;
+const LINK: i32 = 1 << 3;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Span {
+    text: String,
+    style: i32,
+    link: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Block {
+    Heading(usize, Vec<Span>),
+    Paragraph(Vec<Span>),
+    ListItem {
+        depth: usize,
+        number: Option<usize>,
+        spans: Vec<Span>,
+    },
+    Code(String),
+    Rule,
+}
+
+type LinkCallback = Arc<RwLock<Box<dyn Fn(&Gui, &str) + Send + Sync>>>;
+
+/// Renders a subset of Markdown: headings, paragraphs, `**bold**`, `*italic*`, `` `code` ``,
+/// bullet and numbered lists, fenced code blocks, `[links](url)` and horizontal rules.
+#[derive(Clone)]
+pub struct Markdown {
+    source: Arc<RwLock<String>>,
+    blocks: Arc<RwLock<Vec<Block>>>,
+    clicked_link: Arc<RwLock<Option<String>>>,
+    link_callback: LinkCallback,
+}
+
+impl Markdown {
+    pub fn new(source: &str) -> Self {
+        Markdown {
+            source: Arc::new(RwLock::new(String::from(source))),
+            blocks: Arc::new(RwLock::new(parse(source))),
+            clicked_link: Arc::new(RwLock::new(None)),
+            link_callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui, _url: &str| {}))),
+        }
+    }
+
+    /// The callback receives the url of the clicked link.
+    pub fn set_link_callback<T: 'static + Send + Sync + Fn(&Gui, &str)>(
+        mut self,
+        callback: T,
+    ) -> Self {
+        self.link_callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+}
+
+impl Set<String> for Markdown {
+    fn set(&self, value: String) {
+        *self.blocks.blocking_write() = parse(&value);
+        *self.source.blocking_write() = value;
+    }
+}
+
+impl Set<&str> for Markdown {
+    fn set(&self, value: &str) {
+        self.set(String::from(value));
+    }
+}
+
+impl Get<String> for Markdown {
+    fn get(&self) -> String {
+        self.source.blocking_read().clone()
+    }
+}
+
+/// The url of the link clicked during the last frame.
+impl Get<Option<String>> for Markdown {
+    fn get(&self) -> Option<String> {
+        self.clicked_link.blocking_read().clone()
+    }
+}
+
+impl Update for Markdown {
+    fn update(&self, _gui: &Gui) -> bool {
+        let mut clicked = None;
+        for block in self.blocks.blocking_read().iter() {
+            match block {
+                Block::Heading(level, spans) => {
+                    unsafe { ImGui_PushHeadingFont(*level as i32) }
+                    render_spans(spans, false, &mut clicked);
+                    unsafe { ImGui_PopFont() }
+                    if *level <= 2 {
+                        unsafe { ImGui_Separator() }
+                    }
+                }
+                Block::Paragraph(spans) => {
+                    render_spans(spans, false, &mut clicked);
+                    unsafe { ImGui_Spacing() }
+                }
+                Block::ListItem {
+                    depth,
+                    number,
+                    spans,
+                } => {
+                    for _ in 0..*depth {
+                        unsafe { ImGui_Indent(0.0) }
+                    }
+                    let same_line = if let Some(number) = number {
+                        let marker = format!("{}. ", number);
+                        unsafe { ImGui_MarkdownText(marker.as_ptr(), marker.len(), 0, false) };
+                        true
+                    } else {
+                        unsafe { ImGui_Bullet() }
+                        false
+                    };
+                    render_spans(spans, same_line, &mut clicked);
+                    for _ in 0..*depth {
+                        unsafe { ImGui_Unindent(0.0) }
+                    }
+                }
+                Block::Code(code) => unsafe {
+                    ImGui_MarkdownCodeBlock(code.as_ptr(), code.len());
+                    ImGui_Spacing();
+                },
+                Block::Rule => unsafe { ImGui_Separator() },
+            }
+        }
+
+        let link_clicked = clicked.is_some();
+        *self.clicked_link.blocking_write() = clicked;
+        link_clicked
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        let link = self.clicked_link.blocking_read().clone();
+        if let Some(link) = link {
+            (self.link_callback.blocking_read())(gui, &link);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn render_spans(spans: &[Span], mut same_line: bool, clicked: &mut Option<String>) {
+    for span in spans {
+        let text = &span.text;
+        if unsafe { ImGui_MarkdownText(text.as_ptr(), text.len(), span.style, same_line) } {
+            clicked.clone_from(&span.link);
+        }
+        same_line = true;
+    }
+}
+
+fn parse(source: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut paragraph = String::new();
+    let mut lines = source.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            let mut code = String::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                if !code.is_empty() {
+                    code.push('\n');
+                }
+                code.push_str(line);
+            }
+            blocks.push(Block::Code(code));
+        } else if trimmed.is_empty() {
+            flush_paragraph(&mut paragraph, &mut blocks);
+        } else if is_rule(trimmed) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(Block::Rule);
+        } else if let Some((level, text)) = heading(trimmed) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading(level, parse_inline(text)));
+        } else if let Some((number, text)) = list_item(trimmed) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(Block::ListItem {
+                depth: (line.len() - line.trim_start().len()) / 2,
+                number,
+                spans: parse_inline(text),
+            });
+        } else {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(trimmed);
+        }
+    }
+    flush_paragraph(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn flush_paragraph(paragraph: &mut String, blocks: &mut Vec<Block>) {
+    if !paragraph.is_empty() {
+        blocks.push(Block::Paragraph(parse_inline(paragraph)));
+        paragraph.clear();
+    }
+}
+
+fn is_rule(line: &str) -> bool {
+    let mut chars = line.chars().filter(|c| *c != ' ');
+    match chars.next() {
+        Some(first @ ('-' | '*' | '_')) => {
+            let rest: Vec<char> = chars.collect();
+            rest.len() >= 2 && rest.iter().all(|c| *c == first)
+        }
+        _ => false,
+    }
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let text = &line[level..];
+    if text.is_empty() || text.starts_with(' ') {
+        Some((level, text.trim()))
+    } else {
+        None
+    }
+}
+
+fn list_item(line: &str) -> Option<(Option<usize>, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(bullet) {
+            return Some((None, text));
+        }
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        let text = &line[digits..];
+        if let Some(text) = text.strip_prefix(". ").or_else(|| text.strip_prefix(") ")) {
+            return Some((line[..digits].parse().ok(), text));
+        }
+    }
+    None
+}
+
+fn parse_inline(text: &str) -> Vec<Span> {
+    let mut spans = vec![];
+    let mut current = String::new();
+    let mut style = 0;
+    let mut previous: Option<char> = None;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            if let Some(escaped) = rest[1..].chars().next() {
+                current.push(escaped);
+                previous = Some(escaped);
+                rest = &rest[1 + escaped.len_utf8()..];
+                continue;
+            }
+        }
+
+        if c == '`' {
+            if let Some(end) = rest[1..].find('`') {
+                push_span(&mut spans, &mut current, style);
+                spans.push(Span {
+                    text: String::from(&rest[1..end + 1]),
+                    style: style | CODE,
+                    link: None,
+                });
+                previous = Some('`');
+                rest = &rest[end + 2..];
+                continue;
+            }
+        }
+
+        if c == '[' {
+            if let Some((label, url, len)) = link(rest) {
+                push_span(&mut spans, &mut current, style);
+                for span in parse_inline(label) {
+                    spans.push(Span {
+                        style: span.style | style | LINK,
+                        link: Some(String::from(url)),
+                        ..span
+                    });
+                }
+                previous = Some(')');
+                rest = &rest[len..];
+                continue;
+            }
+        }
+
+        if c == '*' || c == '_' {
+            let delimiter = if rest[1..].starts_with(c) {
+                &rest[..2]
+            } else {
+                &rest[..1]
+            };
+            let flag = if delimiter.len() == 2 { BOLD } else { ITALIC };
+            let after = &rest[delimiter.len()..];
+            // underscores inside of words like snake_case are not emphasis
+            let intraword = c == '_'
+                && if style & flag != 0 {
+                    after.chars().next().is_some_and(|n| n.is_alphanumeric())
+                } else {
+                    previous.is_some_and(|p| p.is_alphanumeric())
+                };
+            if !intraword && (style & flag != 0 || after.contains(delimiter)) {
+                push_span(&mut spans, &mut current, style);
+                style ^= flag;
+                previous = Some(c);
+                rest = after;
+                continue;
+            }
+        }
+
+        current.push(c);
+        previous = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    push_span(&mut spans, &mut current, style);
+    spans
+}
+
+fn push_span(spans: &mut Vec<Span>, text: &mut String, style: i32) {
+    if !text.is_empty() {
+        spans.push(Span {
+            text: std::mem::take(text),
+            style,
+            link: None,
+        });
+    }
+}
+
+/// Splits `[label](url)` at the start of `text` into the label, the url and the length of the link.
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    //the label ends at the bracket matching the opening one
+    let mut depth = 0;
+    let mut chars = text.char_indices();
+    let close = loop {
+        match chars.next()? {
+            (_, '\\') => {
+                chars.next();
+            }
+            (_, '[') => depth += 1,
+            (i, ']') => {
+                depth -= 1;
+                if depth == 0 {
+                    break i;
+                }
+            }
+            _ => {}
+        }
+    };
+    let url = text[close + 1..].strip_prefix('(')?;
+    let end = url.find(')')?;
+    Some((&text[1..close], &url[..end], close + end + 3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str, style: i32) -> Span {
+        Span {
+            text: String::from(text),
+            style,
+            link: None,
+        }
+    }
+
+    fn link_span(text: &str, style: i32, url: &str) -> Span {
+        Span {
+            link: Some(String::from(url)),
+            ..span(text, style | LINK)
+        }
+    }
+
+    #[test]
+    fn headings() {
+        assert_eq!(
+            parse("# Title\n### *Sub* title\n#no heading"),
+            vec![
+                Block::Heading(1, vec![span("Title", 0)]),
+                Block::Heading(3, vec![span("Sub", ITALIC), span(" title", 0)]),
+                Block::Paragraph(vec![span("#no heading", 0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn paragraphs_and_rules() {
+        assert_eq!(
+            parse("first\nline\n\nsecond\n---"),
+            vec![
+                Block::Paragraph(vec![span("first line", 0)]),
+                Block::Paragraph(vec![span("second", 0)]),
+                Block::Rule,
+            ]
+        );
+    }
+
+    #[test]
+    fn lists() {
+        assert_eq!(
+            parse("- one\n  * nested\n2. two"),
+            vec![
+                Block::ListItem {
+                    depth: 0,
+                    number: None,
+                    spans: vec![span("one", 0)],
+                },
+                Block::ListItem {
+                    depth: 1,
+                    number: None,
+                    spans: vec![span("nested", 0)],
+                },
+                Block::ListItem {
+                    depth: 0,
+                    number: Some(2),
+                    spans: vec![span("two", 0)],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn code_blocks() {
+        assert_eq!(
+            parse("```rust\nlet a = 1;\n  *b*\n```\ntext"),
+            vec![
+                Block::Code(String::from("let a = 1;\n  *b*")),
+                Block::Paragraph(vec![span("text", 0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn emphasis() {
+        assert_eq!(
+            parse_inline("**bold** *italic* __also bold__ snake_case_name"),
+            vec![
+                span("bold", BOLD),
+                span(" ", 0),
+                span("italic", ITALIC),
+                span(" ", 0),
+                span("also bold", BOLD),
+                span(" snake_case_name", 0),
+            ]
+        );
+        assert_eq!(
+            parse_inline("`*code*` \\*escaped\\* 2 * 3"),
+            vec![span("*code*", CODE), span(" *escaped* 2 * 3", 0)]
+        );
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            parse_inline("see [the **docs**](https://docs.rs)."),
+            vec![
+                span("see ", 0),
+                link_span("the ", 0, "https://docs.rs"),
+                link_span("docs", BOLD, "https://docs.rs"),
+                span(".", 0),
+            ]
+        );
+        assert_eq!(
+            parse_inline("[a] b [c](d)"),
+            vec![span("[a] b ", 0), link_span("c", 0, "d")]
+        );
+    }
+
+    #[test]
+    fn link_brackets() {
+        assert_eq!(link("[a](b) c"), Some(("a", "b", 6)));
+        assert_eq!(link("[[a]](b)"), Some(("[a]", "b", 8)));
+        assert_eq!(link("[a\\]](b)"), Some(("a\\]", "b", 8)));
+        assert_eq!(link("[a] b [c](d)"), None);
+        assert_eq!(link("[a](b"), None);
+    }
+}
@@ -0,0 +1,410 @@
+use crate::backend::*;
+use crate::{Callback, Container2, Get, Gui, Set, Update};
+use std::{any::Any, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+/// A key combination like `"Ctrl+Shift+S"`, shown next to a `MenuItem`.
+#[derive(Clone)]
+struct Shortcut {
+    text: String,
+    //None if the text is not a known key combination, then it is only shown
+    chord: Option<(i32, String)>,
+}
+
+impl Shortcut {
+    fn parse(text: &str) -> Self {
+        let mut display = String::from_str(text).unwrap();
+        if !display.ends_with('\0') {
+            display.push('\0');
+        };
+
+        let mut parts: Vec<&str> = text.trim_end_matches('\0').split('+').collect();
+        let key = parts.pop().unwrap_or_default().trim();
+        let mut mods = 0;
+        for part in parts {
+            mods |= match part.trim().to_lowercase().as_str() {
+                "ctrl" | "control" => KEY_MOD_CTRL,
+                "shift" => KEY_MOD_SHIFT,
+                "alt" => KEY_MOD_ALT,
+                "super" | "cmd" | "win" => KEY_MOD_SUPER,
+                _ => -1,
+            };
+        }
+        //the names of Dear ImGui for keys which are usually abbreviated
+        let key = match key.to_lowercase().as_str() {
+            "del" => "Delete",
+            "ins" => "Insert",
+            "esc" => "Escape",
+            "return" => "Enter",
+            "pgup" => "PageUp",
+            "pgdn" => "PageDown",
+            "up" => "UpArrow",
+            "down" => "DownArrow",
+            "left" => "LeftArrow",
+            "right" => "RightArrow",
+            _ => key,
+        };
+
+        Shortcut {
+            text: display,
+            chord: (mods >= 0 && !key.is_empty()).then(|| (mods, String::from(key))),
+        }
+    }
+
+    /// True in the frame the key combination gets pressed.
+    fn pressed(&self, focused_window_only: bool) -> bool {
+        match &self.chord {
+            Some((mods, key)) => unsafe {
+                ImGui_IsShortcutPressed(*mods, key.as_ptr(), key.len(), focused_window_only)
+            },
+            None => false,
+        }
+    }
+}
+
+/// Activates the enabled items whose shortcut got pressed, including the items of closed menus.
+fn trigger_shortcuts(items: &[Arc<dyn Update>], gui: &Gui, focused_window_only: bool) {
+    for item in items {
+        if let Some(menu) = item.as_any().downcast_ref::<Menu>() {
+            if *menu.enabled.blocking_read() {
+                trigger_shortcuts(&menu.items, gui, focused_window_only);
+            }
+        } else if let Some(item) = item.as_any().downcast_ref::<MenuItem>() {
+            if item.shortcut_pressed(focused_window_only) {
+                item.activate();
+                item.call_callback(gui);
+            }
+        }
+    }
+}
+
+/// The menu bar at the top of the application window, above the docked windows.
+///
+/// Holds `Menu`s and `MenuItem`s. Shortcuts of the items work while any window is focused.
+#[derive(Clone)]
+pub struct MainMenuBar {
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl MainMenuBar {
+    pub fn new() -> Self {
+        MainMenuBar { items: vec![] }
+    }
+}
+
+impl Default for MainMenuBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Container2 for MainMenuBar {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for MainMenuBar {
+    fn update(&self, gui: &Gui) -> bool {
+        trigger_shortcuts(&self.items, gui, false);
+        if unsafe { ImGui_BeginMainMenuBar() } {
+            for widget in &self.items {
+                if widget.update(gui) {
+                    widget.call_callback(gui);
+                }
+            }
+            unsafe { ImGui_EndMainMenuBar() }
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// The menu bar of a `Window`, added with `Window::menu_bar`.
+///
+/// Holds `Menu`s and `MenuItem`s. Shortcuts of the items only work while the window is focused.
+#[derive(Clone)]
+pub struct MenuBar {
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl MenuBar {
+    pub fn new() -> Self {
+        MenuBar { items: vec![] }
+    }
+}
+
+impl Default for MenuBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Container2 for MenuBar {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for MenuBar {
+    fn update(&self, gui: &Gui) -> bool {
+        trigger_shortcuts(&self.items, gui, true);
+        if unsafe { ImGui_BeginMenuBar() } {
+            for widget in &self.items {
+                if widget.update(gui) {
+                    widget.call_callback(gui);
+                }
+            }
+            unsafe { ImGui_EndMenuBar() }
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A menu of a menu bar, or a sub menu if added to another `Menu`. Shows its widgets while open.
+#[derive(Clone)]
+pub struct Menu {
+    label: Arc<RwLock<String>>,
+    enabled: Arc<RwLock<bool>>,
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl Menu {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        Menu {
+            label: Arc::new(RwLock::new(label)),
+            enabled: Arc::new(RwLock::new(true)),
+            items: vec![],
+        }
+    }
+
+    /// A disabled menu can not be opened and the shortcuts of its items are ignored.
+    pub fn enabled(self, enabled: bool) -> Self {
+        self.set_enabled(enabled);
+        self
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        *self.enabled.blocking_write() = enabled;
+    }
+}
+
+impl Container2 for Menu {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for Menu {
+    fn update(&self, gui: &Gui) -> bool {
+        if unsafe {
+            ImGui_BeginMenu(
+                self.label.blocking_read().as_ptr(),
+                *self.enabled.blocking_read(),
+            )
+        } {
+            for widget in &self.items {
+                if widget.update(gui) {
+                    widget.call_callback(gui);
+                }
+            }
+            unsafe { ImGui_EndMenu() }
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// An entry of a `Menu`. The callback is called when the item is clicked or its shortcut is pressed.
+#[derive(Clone)]
+pub struct MenuItem {
+    label: Arc<RwLock<String>>,
+    shortcut: Arc<RwLock<Option<Shortcut>>>,
+    //None if the item has no check mark
+    checked: Arc<RwLock<Option<bool>>>,
+    enabled: Arc<RwLock<bool>>,
+    callback: Callback,
+}
+
+impl MenuItem {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        MenuItem {
+            label: Arc::new(RwLock::new(label)),
+            shortcut: Arc::new(RwLock::new(None)),
+            checked: Arc::new(RwLock::new(None)),
+            enabled: Arc::new(RwLock::new(true)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    /// Shows the key combination next to the label and activates the item when it is pressed,
+    /// e.g. `"Ctrl+S"`, `"Ctrl+Shift+Z"`, `"F5"` or `"Alt+Enter"`. The keys use the names of
+    /// Dear ImGui, unknown combinations are only shown.
+    pub fn shortcut(self, shortcut: &str) -> Self {
+        *self.shortcut.blocking_write() = Some(Shortcut::parse(shortcut));
+        self
+    }
+
+    /// Shows a check mark which is toggled when the item is activated.
+    pub fn checked(self, checked: bool) -> Self {
+        self.set(checked);
+        self
+    }
+
+    /// A disabled item is grayed out and ignores clicks and its shortcut.
+    pub fn enabled(self, enabled: bool) -> Self {
+        self.set_enabled(enabled);
+        self
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        *self.enabled.blocking_write() = enabled;
+    }
+
+    fn shortcut_pressed(&self, focused_window_only: bool) -> bool {
+        *self.enabled.blocking_read()
+            && self
+                .shortcut
+                .blocking_read()
+                .as_ref()
+                .is_some_and(|shortcut| shortcut.pressed(focused_window_only))
+    }
+
+    fn activate(&self) {
+        if let Some(checked) = self.checked.blocking_write().as_mut() {
+            *checked = !*checked;
+        }
+    }
+}
+
+/// True if the item shows a check mark.
+impl Get<bool> for MenuItem {
+    fn get(&self) -> bool {
+        self.checked.blocking_read().unwrap_or(false)
+    }
+}
+
+/// Sets the check mark, items without one get a check mark.
+impl Set<bool> for MenuItem {
+    fn set(&self, value: bool) {
+        *self.checked.blocking_write() = Some(value);
+    }
+}
+
+impl Update for MenuItem {
+    fn update(&self, _gui: &Gui) -> bool {
+        let shortcut = self.shortcut.blocking_read();
+        let shortcut = shortcut
+            .as_ref()
+            .map_or(std::ptr::null(), |shortcut| shortcut.text.as_ptr());
+        let clicked = unsafe {
+            ImGui_MenuItem(
+                self.label.blocking_read().as_ptr(),
+                shortcut,
+                self.checked.blocking_read().unwrap_or(false),
+                *self.enabled.blocking_read(),
+            )
+        };
+        if clicked {
+            self.activate();
+        }
+        clicked
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<T: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: T) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(text: &str) -> Option<(i32, String)> {
+        Shortcut::parse(text).chord
+    }
+
+    #[test]
+    fn modifiers() {
+        assert_eq!(chord("S"), Some((0, String::from("S"))));
+        assert_eq!(
+            chord("Ctrl+Shift+S"),
+            Some((KEY_MOD_CTRL | KEY_MOD_SHIFT, String::from("S")))
+        );
+        assert_eq!(
+            chord("control + alt + F4"),
+            Some((KEY_MOD_CTRL | KEY_MOD_ALT, String::from("F4")))
+        );
+        assert_eq!(chord("Cmd+Q"), Some((KEY_MOD_SUPER, String::from("Q"))));
+    }
+
+    #[test]
+    fn key_aliases() {
+        assert_eq!(chord("Esc"), Some((0, String::from("Escape"))));
+        assert_eq!(
+            chord("Ctrl+del"),
+            Some((KEY_MOD_CTRL, String::from("Delete")))
+        );
+        assert_eq!(
+            chord("Alt+Up"),
+            Some((KEY_MOD_ALT, String::from("UpArrow")))
+        );
+        assert_eq!(chord("PgDn"), Some((0, String::from("PageDown"))));
+    }
+
+    #[test]
+    fn bad_input_is_only_shown() {
+        for text in ["", "Ctrl+", "Hyper+X", "Ctrl+Meta+S"] {
+            let shortcut = Shortcut::parse(text);
+            assert_eq!(shortcut.chord, None, "{}", text);
+            assert_eq!(shortcut.text, format!("{}\0", text));
+        }
+    }
+
+    #[test]
+    fn text_is_null_terminated_once() {
+        assert_eq!(Shortcut::parse("Ctrl+S\0").text, "Ctrl+S\0");
+        assert_eq!(chord("Ctrl+S\0"), Some((KEY_MOD_CTRL, String::from("S"))));
+    }
+}
@@ -0,0 +1,222 @@
+use crate::backend::*;
+use crate::{Get, Gui, Set, Update};
+use std::{any::Any, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+const DEFAULT_CAPACITY: usize = 100;
+
+/// Keeps the latest `capacity` samples. Once full, new samples overwrite the oldest one at `offset`.
+struct RingBuffer {
+    values: Vec<f32>,
+    capacity: usize,
+    //index of the oldest sample
+    offset: usize,
+}
+
+impl RingBuffer {
+    fn new(capacity: usize) -> Self {
+        RingBuffer {
+            values: Vec::with_capacity(capacity),
+            capacity,
+            offset: 0,
+        }
+    }
+
+    fn push(&mut self, value: f32) {
+        if self.values.len() < self.capacity {
+            self.values.push(value);
+        } else if self.capacity > 0 {
+            self.values[self.offset] = value;
+            self.offset = (self.offset + 1) % self.capacity;
+        }
+    }
+
+    /// The samples from the oldest to the latest.
+    fn to_vec(&self) -> Vec<f32> {
+        let (newer, older) = self.values.split_at(self.offset);
+        older.iter().chain(newer).copied().collect()
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        let mut values = self.to_vec();
+        values.drain(..values.len().saturating_sub(capacity));
+        *self = RingBuffer::new(capacity);
+        self.values.extend(values);
+    }
+}
+
+/// Plots the latest samples as lines (`PlotLines`) or bars (`PlotHistogram`).
+///
+/// Samples are added with `push` which can be called from any thread, e.g. from a thread reading
+/// a sensor. Only the latest `capacity` samples are kept.
+#[derive(Clone)]
+pub struct SamplePlot<const HISTOGRAM: bool> {
+    label: Arc<RwLock<String>>,
+    samples: Arc<RwLock<RingBuffer>>,
+    //None scales to the minimum and maximum of the samples
+    scale: Arc<RwLock<Option<(f32, f32)>>>,
+    overlay: Arc<RwLock<Option<String>>>,
+    size: Arc<RwLock<ImGui_Vec2>>,
+}
+
+pub type PlotLines = SamplePlot<false>;
+pub type PlotHistogram = SamplePlot<true>;
+
+impl<const HISTOGRAM: bool> SamplePlot<HISTOGRAM> {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        SamplePlot {
+            label: Arc::new(RwLock::new(label)),
+            samples: Arc::new(RwLock::new(RingBuffer::new(DEFAULT_CAPACITY))),
+            scale: Arc::new(RwLock::new(None)),
+            overlay: Arc::new(RwLock::new(None)),
+            size: Arc::new(RwLock::new(ImGui_Vec2::default())),
+        }
+    }
+
+    /// The number of samples shown, older samples are dropped.
+    pub fn capacity(self, capacity: usize) -> Self {
+        self.samples.blocking_write().set_capacity(capacity);
+        self
+    }
+
+    /// Fixes the vertical range instead of scaling to the samples.
+    pub fn scale(self, min: f32, max: f32) -> Self {
+        self.set_scale(Some((min, max)));
+        self
+    }
+
+    /// None scales to the minimum and maximum of the samples.
+    pub fn set_scale(&self, scale: Option<(f32, f32)>) {
+        *self.scale.blocking_write() = scale;
+    }
+
+    /// Text shown at the top of the plot.
+    pub fn overlay(self, text: &str) -> Self {
+        self.set_overlay(text);
+        self
+    }
+
+    pub fn set_overlay(&self, text: &str) {
+        *self.overlay.blocking_write() = Some(String::from(text));
+    }
+
+    /// A size of zero uses the default item width and a height fitting the label.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        *self.size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
+
+    /// Adds a sample, can be called from any thread.
+    pub fn push(&self, value: f32) {
+        self.samples.blocking_write().push(value);
+    }
+
+    pub fn clear(&self) {
+        let mut samples = self.samples.blocking_write();
+        *samples = RingBuffer::new(samples.capacity);
+    }
+}
+
+/// The samples from the oldest to the latest.
+impl<const HISTOGRAM: bool> Get<Vec<f32>> for SamplePlot<HISTOGRAM> {
+    fn get(&self) -> Vec<f32> {
+        self.samples.blocking_read().to_vec()
+    }
+}
+
+/// Replaces the samples, only the latest `capacity` ones are kept.
+impl<const HISTOGRAM: bool> Set<Vec<f32>> for SamplePlot<HISTOGRAM> {
+    fn set(&self, value: Vec<f32>) {
+        let mut samples = self.samples.blocking_write();
+        *samples = RingBuffer::new(samples.capacity);
+        for sample in value {
+            samples.push(sample);
+        }
+    }
+}
+
+impl<const HISTOGRAM: bool> Update for SamplePlot<HISTOGRAM> {
+    fn update(&self, _gui: &Gui) -> bool {
+        let samples = self.samples.blocking_read();
+        let overlay = self.overlay.blocking_read();
+        let (overlay, overlay_len) = match overlay.as_ref() {
+            Some(text) => (text.as_ptr(), text.len()),
+            None => (std::ptr::null(), 0),
+        };
+        //f32::MAX lets Dear ImGui scale to the samples
+        let (scale_min, scale_max) = self.scale.blocking_read().unwrap_or((f32::MAX, f32::MAX));
+        unsafe {
+            ImGui_PlotSamples(
+                self.label.blocking_read().as_ptr(),
+                samples.values.as_ptr(),
+                samples.values.len(),
+                samples.offset,
+                overlay,
+                overlay_len,
+                scale_min,
+                scale_max,
+                *self.size.blocking_read(),
+                HISTOGRAM,
+            )
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(capacity: usize, values: &[f32]) -> RingBuffer {
+        let mut buffer = RingBuffer::new(capacity);
+        for value in values {
+            buffer.push(*value);
+        }
+        buffer
+    }
+
+    #[test]
+    fn keeps_the_latest_samples() {
+        assert_eq!(buffer(3, &[1.0, 2.0]).to_vec(), [1.0, 2.0]);
+        assert_eq!(buffer(3, &[1.0, 2.0, 3.0]).to_vec(), [1.0, 2.0, 3.0]);
+        let wrapped = buffer(3, &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(wrapped.values.len(), 3);
+        assert_eq!(wrapped.to_vec(), [3.0, 4.0, 5.0]);
+        //a full turn ends at the same offset
+        assert_eq!(
+            buffer(3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).to_vec(),
+            [4.0, 5.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn small_capacities() {
+        assert!(buffer(0, &[1.0, 2.0]).to_vec().is_empty());
+        assert_eq!(buffer(1, &[1.0, 2.0, 3.0]).to_vec(), [3.0]);
+    }
+
+    #[test]
+    fn set_capacity_keeps_the_latest_samples() {
+        let mut samples = buffer(4, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        samples.set_capacity(2);
+        assert_eq!(samples.to_vec(), [5.0, 6.0]);
+        samples.set_capacity(3);
+        samples.push(7.0);
+        samples.push(8.0);
+        assert_eq!(samples.to_vec(), [6.0, 7.0, 8.0]);
+        samples.set_capacity(0);
+        assert!(samples.to_vec().is_empty());
+    }
+}
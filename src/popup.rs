@@ -0,0 +1,335 @@
+use crate::backend::*;
+use crate::{Button, Container2, Get, Gui, Update};
+use std::{any::Any, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+type ResultCallback = Arc<RwLock<Box<dyn Fn(&Gui, Option<&str>) + Send + Sync>>>;
+
+/// A floating container which is shown above the windows until the user clicks outside of it.
+///
+/// Opened with `open` or `gui.open_popup(id)`, which can be called from callbacks and other threads.
+#[derive(Clone)]
+pub struct Popup {
+    id: Arc<RwLock<String>>,
+    open_request: Arc<RwLock<bool>>,
+    close_request: Arc<RwLock<bool>>,
+    is_open: Arc<RwLock<bool>>,
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl Popup {
+    pub fn new(id: &str) -> Self {
+        let mut id = String::from_str(id).unwrap();
+        if !id.ends_with('\0') {
+            id.push('\0');
+        };
+
+        Popup {
+            id: Arc::new(RwLock::new(id)),
+            open_request: Arc::new(RwLock::new(false)),
+            close_request: Arc::new(RwLock::new(false)),
+            is_open: Arc::new(RwLock::new(false)),
+            items: vec![],
+        }
+    }
+
+    /// Opens the popup in the next frame.
+    pub fn open(&self) {
+        *self.open_request.blocking_write() = true;
+    }
+
+    /// Closes the popup in the next frame.
+    pub fn close(&self) {
+        *self.close_request.blocking_write() = true;
+    }
+}
+
+/// True while the popup is open.
+impl Get<bool> for Popup {
+    fn get(&self) -> bool {
+        *self.is_open.blocking_read()
+    }
+}
+
+impl Container2 for Popup {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for Popup {
+    fn update(&self, gui: &Gui) -> bool {
+        let id = self.id.blocking_read();
+        //has to be opened with the same ID stack as it is shown
+        if std::mem::take(&mut *self.open_request.blocking_write()) || gui.take_popup_request(&id) {
+            unsafe { ImGui_OpenPopup(id.as_ptr()) }
+        }
+
+        let close = std::mem::take(&mut *self.close_request.blocking_write());
+        let visible = unsafe { ImGui_BeginPopup(id.as_ptr()) };
+        if visible {
+            for widget in &self.items {
+                if widget.update(gui) {
+                    widget.call_callback(gui);
+                }
+            }
+            if close {
+                unsafe { ImGui_CloseCurrentPopup() }
+            }
+            unsafe { ImGui_EndPopup() }
+        }
+        *self.is_open.blocking_write() = visible && !close;
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A dialog which blocks the input to the other windows until it is closed.
+///
+/// The buttons added with `button` are shown below the widgets and close the dialog. The result
+/// callback gets the label of the clicked button, or None if the dialog was closed otherwise.
+#[derive(Clone)]
+pub struct PopupModal {
+    title: Arc<RwLock<String>>,
+    closable: Arc<RwLock<bool>>,
+    open_request: Arc<RwLock<bool>>,
+    //the result passed to close, None if closed without a result
+    close_request: Arc<RwLock<Option<Option<String>>>>,
+    is_open: Arc<RwLock<bool>>,
+    result: Arc<RwLock<Option<String>>>,
+    items: Vec<Arc<dyn Update>>,
+    buttons: Vec<(String, Button)>,
+    result_callback: ResultCallback,
+}
+
+impl PopupModal {
+    pub fn new(title: &str) -> Self {
+        let mut title = String::from_str(title).unwrap();
+        if !title.ends_with('\0') {
+            title.push('\0');
+        };
+
+        PopupModal {
+            title: Arc::new(RwLock::new(title)),
+            closable: Arc::new(RwLock::new(false)),
+            open_request: Arc::new(RwLock::new(false)),
+            close_request: Arc::new(RwLock::new(None)),
+            is_open: Arc::new(RwLock::new(false)),
+            result: Arc::new(RwLock::new(None)),
+            items: vec![],
+            buttons: vec![],
+            result_callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui, _result| {}))),
+        }
+    }
+
+    /// Adds a button which closes the dialog with its label as result.
+    pub fn button(mut self, label: &str) -> Self {
+        self.buttons.push((String::from(label), Button::new(label)));
+        self
+    }
+
+    /// Shows a close button in the title bar, closing the dialog without a result.
+    pub fn closable(self) -> Self {
+        *self.closable.blocking_write() = true;
+        self
+    }
+
+    /// Called when the dialog gets closed, with the label of the clicked button or the result
+    /// passed to `close`.
+    pub fn set_result_callback<T: 'static + Send + Sync + Fn(&Gui, Option<&str>)>(
+        mut self,
+        callback: T,
+    ) -> Self {
+        self.result_callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    /// Opens the dialog in the next frame.
+    pub fn open(&self) {
+        *self.open_request.blocking_write() = true;
+    }
+
+    /// Closes the dialog in the next frame, e.g. from the callback of one of its widgets.
+    pub fn close(&self, result: Option<&str>) {
+        *self.close_request.blocking_write() = Some(result.map(String::from));
+    }
+}
+
+/// True while the dialog is open.
+impl Get<bool> for PopupModal {
+    fn get(&self) -> bool {
+        *self.is_open.blocking_read()
+    }
+}
+
+/// The result the dialog was closed with the last time.
+impl Get<Option<String>> for PopupModal {
+    fn get(&self) -> Option<String> {
+        self.result.blocking_read().clone()
+    }
+}
+
+impl Container2 for PopupModal {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for PopupModal {
+    fn update(&self, gui: &Gui) -> bool {
+        let title = self.title.blocking_read();
+        //has to be opened with the same ID stack as it is shown
+        if std::mem::take(&mut *self.open_request.blocking_write())
+            || gui.take_popup_request(&title)
+        {
+            unsafe { ImGui_OpenPopup(title.as_ptr()) }
+        }
+
+        let close_request = self.close_request.blocking_write().take();
+        let closable = *self.closable.blocking_read();
+        let mut open = true;
+        let visible = unsafe {
+            ImGui_BeginPopupModal(
+                title.as_ptr(),
+                if closable {
+                    &mut open
+                } else {
+                    std::ptr::null_mut()
+                },
+            )
+        };
+
+        let mut result = None;
+        if visible {
+            for widget in &self.items {
+                if widget.update(gui) {
+                    widget.call_callback(gui);
+                }
+            }
+            if !self.buttons.is_empty() {
+                unsafe { ImGui_Separator() }
+            }
+            for (i, (label, button)) in self.buttons.iter().enumerate() {
+                if i > 0 {
+                    unsafe { ImGui_SameLine(0.0, -1.0) }
+                }
+                if button.update(gui) {
+                    result = Some(Some(label.clone()));
+                }
+            }
+            result = result.or(close_request);
+            if result.is_some() {
+                unsafe { ImGui_CloseCurrentPopup() }
+            }
+            unsafe { ImGui_EndPopup() }
+        }
+        //the close button of the title bar
+        if !open {
+            result = Some(None);
+        }
+
+        *self.is_open.blocking_write() = visible && result.is_none();
+        match result {
+            Some(result) => {
+                *self.result.blocking_write() = result;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        let result = self.result.blocking_read().clone();
+        (self.result_callback.blocking_read())(gui, result.as_deref());
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ContextMenuKind {
+    Item,
+    Window,
+}
+
+/// A popup opened by right clicking the previous widget (`item`) or the window (`window`),
+/// usually holding `MenuItem`s.
+#[derive(Clone)]
+pub struct ContextMenu {
+    id: Arc<RwLock<String>>,
+    kind: ContextMenuKind,
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl ContextMenu {
+    /// Opens when the widget added before the context menu is right clicked.
+    pub fn item(id: &str) -> Self {
+        ContextMenu::new(id, ContextMenuKind::Item)
+    }
+
+    /// Opens when the empty space of the window is right clicked.
+    pub fn window(id: &str) -> Self {
+        ContextMenu::new(id, ContextMenuKind::Window)
+    }
+
+    fn new(id: &str, kind: ContextMenuKind) -> Self {
+        let mut id = String::from_str(id).unwrap();
+        if !id.ends_with('\0') {
+            id.push('\0');
+        };
+
+        ContextMenu {
+            id: Arc::new(RwLock::new(id)),
+            kind,
+            items: vec![],
+        }
+    }
+}
+
+impl Container2 for ContextMenu {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for ContextMenu {
+    fn update(&self, gui: &Gui) -> bool {
+        let id = self.id.blocking_read();
+        let visible = unsafe {
+            match self.kind {
+                ContextMenuKind::Item => ImGui_BeginPopupContextItem(id.as_ptr()),
+                ContextMenuKind::Window => ImGui_BeginPopupContextWindow(id.as_ptr()),
+            }
+        };
+        if visible {
+            for widget in &self.items {
+                if widget.update(gui) {
+                    widget.call_callback(gui);
+                }
+            }
+            unsafe { ImGui_EndPopup() }
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,166 @@
+use crate::backend::*;
+use crate::{Get, Gui, Set, Update};
+use std::{
+    any::Any,
+    sync::Arc,
+    thread::{self, JoinHandle},
+};
+use tokio::sync::RwLock;
+
+/// Shows the progress of a task as a filled bar. The overlay text defaults to the percentage.
+#[derive(Clone)]
+pub struct ProgressBar {
+    fraction: Arc<RwLock<f32>>,
+    overlay: Arc<RwLock<Option<String>>>,
+    size: Arc<RwLock<ImGui_Vec2>>,
+    indeterminate: Arc<RwLock<bool>>,
+}
+
+impl ProgressBar {
+    pub fn new() -> Self {
+        ProgressBar {
+            fraction: Arc::new(RwLock::new(0.0)),
+            overlay: Arc::new(RwLock::new(None)),
+            //a negative width fills the available width
+            size: Arc::new(RwLock::new(ImGui_Vec2 { x: -1.0, y: 0.0 })),
+            indeterminate: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    pub fn overlay(self, text: &str) -> Self {
+        self.set(text);
+        self
+    }
+
+    /// A width of zero uses the default item width, a negative width fills the available width
+    /// except the given amount. A height of zero uses the frame height.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        *self.size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
+
+    /// Shows an animation instead of the fraction until a fraction is set.
+    pub fn indeterminate(self) -> Self {
+        self.set_indeterminate(true);
+        self
+    }
+
+    pub fn set_indeterminate(&self, indeterminate: bool) {
+        *self.indeterminate.blocking_write() = indeterminate;
+    }
+
+    /// Removes the overlay text so the percentage is shown again.
+    pub fn clear_overlay(&self) {
+        *self.overlay.blocking_write() = None;
+    }
+
+    /// Runs `work` in a new thread. It receives a `Progress` to update this bar, `done` is called
+    /// with the result in the same thread afterwards.
+    pub fn spawn<R, W, D>(&self, work: W, done: D) -> JoinHandle<()>
+    where
+        W: 'static + Send + FnOnce(Progress) -> R,
+        D: 'static + Send + FnOnce(R),
+    {
+        self.set(0.0);
+        self.clear_overlay();
+        let progress = Progress { bar: self.clone() };
+        thread::spawn(move || {
+            let result = work(progress);
+            done(result);
+        })
+    }
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The fraction in the range 0.0 to 1.0, ends the indeterminate mode.
+impl Set<f32> for ProgressBar {
+    fn set(&self, value: f32) {
+        *self.fraction.blocking_write() = value.clamp(0.0, 1.0);
+        *self.indeterminate.blocking_write() = false;
+    }
+}
+
+/// The overlay text.
+impl Set<String> for ProgressBar {
+    fn set(&self, value: String) {
+        *self.overlay.blocking_write() = Some(value);
+    }
+}
+
+/// The overlay text.
+impl Set<&str> for ProgressBar {
+    fn set(&self, value: &str) {
+        self.set(String::from(value));
+    }
+}
+
+impl Get<f32> for ProgressBar {
+    fn get(&self) -> f32 {
+        *self.fraction.blocking_read()
+    }
+}
+
+impl Update for ProgressBar {
+    fn update(&self, _gui: &Gui) -> bool {
+        let overlay = self.overlay.blocking_read();
+        let (text, len) = match overlay.as_ref() {
+            Some(text) => (text.as_ptr(), text.len()),
+            None => (std::ptr::null(), 0),
+        };
+        unsafe {
+            ImGui_ProgressBar(
+                *self.fraction.blocking_read(),
+                *self.size.blocking_read(),
+                text,
+                len,
+                *self.indeterminate.blocking_read(),
+            )
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Handed to the work of `ProgressBar::spawn` to report its progress from the worker thread.
+#[derive(Clone)]
+pub struct Progress {
+    bar: ProgressBar,
+}
+
+impl Progress {
+    /// Sets the fraction in the range 0.0 to 1.0.
+    pub fn set(&self, fraction: f32) {
+        self.bar.set(fraction);
+    }
+
+    /// Sets the fraction to `done / total`.
+    pub fn set_steps(&self, done: usize, total: usize) {
+        if total > 0 {
+            self.bar.set(done as f32 / total as f32);
+        }
+    }
+
+    pub fn set_overlay(&self, text: &str) {
+        self.bar.set(text);
+    }
+
+    /// Shows the percentage again.
+    pub fn clear_overlay(&self) {
+        self.bar.clear_overlay();
+    }
+
+    pub fn set_indeterminate(&self) {
+        self.bar.set_indeterminate(true);
+    }
+}
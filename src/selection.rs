@@ -0,0 +1,831 @@
+use crate::backend::*;
+use crate::{Callback, Get, Gui, Set, Update};
+use std::{any::Any, collections::BTreeSet, marker::PhantomData, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+const FILTER_CAPACITY: usize = 256;
+
+/// A fieldless enum which can be selected with a typed `Combo` or `RadioGroup`.
+/// Usually implemented with `#[derive(GuiEnum)]`, which rejects enums without variants.
+pub trait GuiEnum: Copy + PartialEq + Send + Sync + 'static {
+    /// All variants in the order they are shown, at least one.
+    fn variants() -> &'static [Self];
+    fn label(&self) -> &'static str;
+}
+
+/// Constructs a selection widget whose items are the variants of a `GuiEnum`,
+/// e.g. `Combo::<Mode>::new("mode")`.
+pub trait EnumWidget {
+    fn new(label: &str) -> Self;
+}
+
+fn enum_labels<T: GuiEnum>() -> Vec<String> {
+    T::variants()
+        .iter()
+        .map(|variant| String::from(variant.label()))
+        .collect()
+}
+
+fn enum_index<T: GuiEnum>(value: T) -> usize {
+    T::variants()
+        .iter()
+        .position(|variant| *variant == value)
+        .unwrap_or(0)
+}
+
+//falls back to the first variant if the index is out of range, e.g. after the items were replaced
+fn enum_variant<T: GuiEnum>(index: usize) -> Option<T> {
+    let variants = T::variants();
+    variants.get(index).or(variants.first()).copied()
+}
+
+/// The items of a `Combo` or `ListBox` together with the selected index. Shared between the
+/// widget clones so the items can be replaced from any thread.
+struct ItemList {
+    items: Vec<String>,
+    //in multi select mode the last item clicked without shift, the anchor of range selections
+    selected: usize,
+    multi_select: bool,
+    selection: BTreeSet<usize>,
+    //null terminated filter text, None if filtering is disabled
+    filter: Option<Vec<u8>>,
+}
+
+impl ItemList {
+    fn new() -> Self {
+        ItemList {
+            items: vec![],
+            selected: 0,
+            multi_select: false,
+            selection: BTreeSet::new(),
+            filter: None,
+        }
+    }
+
+    /// Replaces the items and keeps the selected items selected if they are still part of the list.
+    fn set_items(&mut self, items: Vec<String>) {
+        let position = |index: &usize| {
+            let item = self.items.get(*index)?;
+            items.iter().position(|i| i == item)
+        };
+        self.selected = position(&self.selected).unwrap_or(0);
+        self.selection = self.selection.iter().filter_map(position).collect();
+        self.items = items;
+    }
+
+    /// The selected indices, in single select mode only the selected item.
+    fn selection(&self) -> Vec<usize> {
+        if self.multi_select {
+            self.selection.iter().copied().collect()
+        } else if self.selected < self.items.len() {
+            vec![self.selected]
+        } else {
+            vec![]
+        }
+    }
+
+    fn set_selection(&mut self, mut selection: Vec<usize>) {
+        selection.retain(|index| *index < self.items.len());
+        if let Some(first) = selection.first() {
+            self.selected = *first;
+        }
+        self.selection = selection.into_iter().collect();
+    }
+
+    fn select_index(&mut self, index: usize) {
+        if index < self.items.len() {
+            self.selected = index;
+        }
+    }
+
+    fn select(&mut self, item: &str) {
+        if let Some(index) = self.items.iter().position(|i| i == item) {
+            self.selected = index;
+        }
+    }
+
+    fn selected_item(&self) -> Option<String> {
+        self.items.get(self.selected).cloned()
+    }
+
+    fn filter_text(&self) -> String {
+        match &self.filter {
+            Some(filter) => {
+                let len = filter.iter().position(|c| *c == 0).unwrap_or(filter.len());
+                String::from_utf8_lossy(&filter[..len]).to_lowercase()
+            }
+            None => String::new(),
+        }
+    }
+
+    /// Draws the filter input if enabled.
+    fn update_filter(&mut self, label: &str) {
+        if let Some(filter) = &mut self.filter {
+            let id = format!("##filter{}", label);
+            unsafe { ImGui_InputFilter(id.as_ptr(), filter.as_mut_ptr(), filter.len()) };
+        }
+    }
+
+    /// Draws all items matching the filter as selectables, returns true if the selection changed.
+    fn update_items(&mut self) -> bool {
+        let filter = self.filter_text();
+        let visible: Vec<usize> = (0..self.items.len())
+            .filter(|i| filter.is_empty() || self.items[*i].to_lowercase().contains(&filter))
+            .collect();
+
+        let mut clicked = None;
+        for i in visible.iter().copied() {
+            let item = &self.items[i];
+            let selected = if self.multi_select {
+                self.selection.contains(&i)
+            } else {
+                i == self.selected
+            };
+            unsafe {
+                ImGui_PushID(i as i32);
+                let events = ImGui_Selectable(
+                    item.as_ptr(),
+                    item.len(),
+                    selected,
+                    0,
+                    ImGui_Vec2::default(),
+                );
+                if events & SELECTABLE_CLICKED != 0 {
+                    clicked = Some(i);
+                }
+                if selected {
+                    ImGui_SetItemDefaultFocus();
+                }
+                ImGui_PopID();
+            }
+        }
+
+        let Some(clicked) = clicked else {
+            return false;
+        };
+        let mods = unsafe { ImGui_GetKeyMods() };
+        self.click(clicked, &visible, mods)
+    }
+
+    /// Applies a click on the item, returns true if the selection changed. In multi select mode
+    /// ctrl toggles an item, shift selects the visible items between the anchor and the clicked item.
+    fn click(&mut self, clicked: usize, visible: &[usize], mods: i32) -> bool {
+        if !self.multi_select {
+            let changed = clicked != self.selected;
+            self.selected = clicked;
+            return changed;
+        }
+
+        if mods & KEY_MOD_SHIFT != 0 {
+            if mods & KEY_MOD_CTRL == 0 {
+                self.selection.clear();
+            }
+            let range = self.selected.min(clicked)..=self.selected.max(clicked);
+            self.selection
+                .extend(visible.iter().filter(|i| range.contains(i)));
+        } else if mods & KEY_MOD_CTRL != 0 {
+            if !self.selection.remove(&clicked) {
+                self.selection.insert(clicked);
+            }
+            self.selected = clicked;
+        } else {
+            self.selection.clear();
+            self.selection.insert(clicked);
+            self.selected = clicked;
+        }
+        true
+    }
+}
+
+fn item_list(label: &str) -> (String, Arc<RwLock<ItemList>>) {
+    let mut label = String::from_str(label).unwrap();
+    if !label.ends_with('\0') {
+        label.push('\0');
+    };
+    (label, Arc::new(RwLock::new(ItemList::new())))
+}
+
+/// A drop down list showing the selected item.
+///
+/// `Combo::new` lists strings set with `items`, `Combo::<T>::new` lists the variants of a `GuiEnum`.
+pub struct Combo<T = String> {
+    label: Arc<RwLock<String>>,
+    list: Arc<RwLock<ItemList>>,
+    callback: Callback,
+    value_type: PhantomData<fn() -> T>,
+}
+
+//derive(Clone) would require T: Clone
+impl<T> Clone for Combo<T> {
+    fn clone(&self) -> Self {
+        Combo {
+            label: self.label.clone(),
+            list: self.list.clone(),
+            callback: self.callback.clone(),
+            value_type: PhantomData,
+        }
+    }
+}
+
+impl Combo {
+    pub fn new(label: &str) -> Self {
+        let (label, list) = item_list(label);
+        Combo {
+            label: Arc::new(RwLock::new(label)),
+            list,
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+            value_type: PhantomData,
+        }
+    }
+
+    pub fn items<S: Into<String>>(self, items: impl IntoIterator<Item = S>) -> Self {
+        self.set_items(items);
+        self
+    }
+
+    /// Replaces the items, can be called from any thread.
+    pub fn set_items<S: Into<String>>(&self, items: impl IntoIterator<Item = S>) {
+        let items = items.into_iter().map(Into::into).collect();
+        self.list.blocking_write().set_items(items);
+    }
+}
+
+impl<T: GuiEnum> EnumWidget for Combo<T> {
+    fn new(label: &str) -> Self {
+        let (label, list) = item_list(label);
+        list.blocking_write().items = enum_labels::<T>();
+        Combo {
+            label: Arc::new(RwLock::new(label)),
+            list,
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+            value_type: PhantomData,
+        }
+    }
+}
+
+impl<T> Combo<T> {
+    /// Shows an input at the top of the popup which hides the items not containing its text.
+    pub fn filter(self) -> Self {
+        self.list.blocking_write().filter = Some(vec![0; FILTER_CAPACITY]);
+        self
+    }
+}
+
+/// The selected variant, panics if `T::variants` is empty.
+impl<T: GuiEnum> Get<T> for Combo<T> {
+    fn get(&self) -> T {
+        enum_variant(self.list.blocking_read().selected)
+            .expect("a GuiEnum needs at least one variant")
+    }
+}
+
+impl<T: GuiEnum> Set<T> for Combo<T> {
+    fn set(&self, value: T) {
+        self.list.blocking_write().selected = enum_index(value);
+    }
+}
+
+/// The index of the selected item.
+impl<T> Get<usize> for Combo<T> {
+    fn get(&self) -> usize {
+        self.list.blocking_read().selected
+    }
+}
+
+/// The selected item, None if there are no items.
+impl<T> Get<Option<String>> for Combo<T> {
+    fn get(&self) -> Option<String> {
+        self.list.blocking_read().selected_item()
+    }
+}
+
+/// Selects the item with the index, does nothing if the index is out of range.
+impl<T> Set<usize> for Combo<T> {
+    fn set(&self, value: usize) {
+        self.list.blocking_write().select_index(value);
+    }
+}
+
+/// Selects the item with the text, does nothing if there is no such item.
+impl<T> Set<&str> for Combo<T> {
+    fn set(&self, value: &str) {
+        self.list.blocking_write().select(value);
+    }
+}
+
+impl<T: 'static> Update for Combo<T> {
+    fn update(&self, _gui: &Gui) -> bool {
+        let label = self.label.blocking_read();
+        let mut list = self.list.blocking_write();
+        let preview = list.selected_item().unwrap_or_default();
+        if !unsafe { ImGui_BeginCombo(label.as_ptr(), preview.as_ptr(), preview.len()) } {
+            return false;
+        }
+        list.update_filter(&label);
+        let changed = list.update_items();
+        unsafe { ImGui_EndCombo() }
+        changed
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: F) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A scrollable frame listing all items.
+#[derive(Clone)]
+pub struct ListBox {
+    label: Arc<RwLock<String>>,
+    list: Arc<RwLock<ItemList>>,
+    size: Arc<RwLock<ImGui_Vec2>>,
+    callback: Callback,
+}
+
+impl ListBox {
+    pub fn new(label: &str) -> Self {
+        let (label, list) = item_list(label);
+        ListBox {
+            label: Arc::new(RwLock::new(label)),
+            list,
+            size: Arc::new(RwLock::new(ImGui_Vec2::default())),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    pub fn items<S: Into<String>>(self, items: impl IntoIterator<Item = S>) -> Self {
+        self.set_items(items);
+        self
+    }
+
+    /// Replaces the items, can be called from any thread.
+    pub fn set_items<S: Into<String>>(&self, items: impl IntoIterator<Item = S>) {
+        let items = items.into_iter().map(Into::into).collect();
+        self.list.blocking_write().set_items(items);
+    }
+
+    /// Shows an input above the list which hides the items not containing its text.
+    pub fn filter(self) -> Self {
+        self.list.blocking_write().filter = Some(vec![0; FILTER_CAPACITY]);
+        self
+    }
+
+    /// Allows selecting several items, ctrl+click toggles an item and shift+click selects a range.
+    pub fn multi_select(self) -> Self {
+        self.list.blocking_write().multi_select = true;
+        self
+    }
+
+    /// A size of zero uses the default width and a height of about 7 items.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        *self.size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
+}
+
+/// The index of the selected item.
+impl Get<usize> for ListBox {
+    fn get(&self) -> usize {
+        self.list.blocking_read().selected
+    }
+}
+
+/// The selected item, None if there are no items.
+impl Get<Option<String>> for ListBox {
+    fn get(&self) -> Option<String> {
+        self.list.blocking_read().selected_item()
+    }
+}
+
+/// The indices of the selected items.
+impl Get<Vec<usize>> for ListBox {
+    fn get(&self) -> Vec<usize> {
+        self.list.blocking_read().selection()
+    }
+}
+
+/// The selected items.
+impl Get<Vec<String>> for ListBox {
+    fn get(&self) -> Vec<String> {
+        let list = self.list.blocking_read();
+        list.selection()
+            .into_iter()
+            .filter_map(|i| list.items.get(i).cloned())
+            .collect()
+    }
+}
+
+/// Selects the items with the indices, in single select mode only the first one.
+/// Indices which are out of range are ignored.
+impl Set<Vec<usize>> for ListBox {
+    fn set(&self, value: Vec<usize>) {
+        self.list.blocking_write().set_selection(value);
+    }
+}
+
+/// Selects the item with the index, does nothing if the index is out of range.
+impl Set<usize> for ListBox {
+    fn set(&self, value: usize) {
+        self.list.blocking_write().select_index(value);
+    }
+}
+
+/// Selects the item with the text, does nothing if there is no such item.
+impl Set<&str> for ListBox {
+    fn set(&self, value: &str) {
+        self.list.blocking_write().select(value);
+    }
+}
+
+impl Update for ListBox {
+    fn update(&self, _gui: &Gui) -> bool {
+        let label = self.label.blocking_read();
+        let mut list = self.list.blocking_write();
+        list.update_filter(&label);
+        if !unsafe { ImGui_BeginListBox(label.as_ptr(), *self.size.blocking_read()) } {
+            return false;
+        }
+        let changed = list.update_items();
+        unsafe { ImGui_EndListBox() }
+        changed
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: F) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A group of radio buttons of which exactly one is active.
+///
+/// `RadioGroup::new` shows strings set with `items`, `RadioGroup::<T>::new` shows the variants of a
+/// `GuiEnum`. The visible part of the label is shown before the buttons.
+pub struct RadioGroup<T = String> {
+    label: Arc<RwLock<String>>,
+    list: Arc<RwLock<ItemList>>,
+    horizontal: Arc<RwLock<bool>>,
+    callback: Callback,
+    value_type: PhantomData<fn() -> T>,
+}
+
+//derive(Clone) would require T: Clone
+impl<T> Clone for RadioGroup<T> {
+    fn clone(&self) -> Self {
+        RadioGroup {
+            label: self.label.clone(),
+            list: self.list.clone(),
+            horizontal: self.horizontal.clone(),
+            callback: self.callback.clone(),
+            value_type: PhantomData,
+        }
+    }
+}
+
+impl RadioGroup {
+    pub fn new(label: &str) -> Self {
+        let (label, list) = item_list(label);
+        RadioGroup {
+            label: Arc::new(RwLock::new(label)),
+            list,
+            horizontal: Arc::new(RwLock::new(false)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+            value_type: PhantomData,
+        }
+    }
+
+    pub fn items<S: Into<String>>(self, items: impl IntoIterator<Item = S>) -> Self {
+        self.set_items(items);
+        self
+    }
+
+    /// Replaces the items, can be called from any thread.
+    pub fn set_items<S: Into<String>>(&self, items: impl IntoIterator<Item = S>) {
+        let items = items.into_iter().map(Into::into).collect();
+        self.list.blocking_write().set_items(items);
+    }
+}
+
+impl<T: GuiEnum> EnumWidget for RadioGroup<T> {
+    fn new(label: &str) -> Self {
+        let (label, list) = item_list(label);
+        list.blocking_write().items = enum_labels::<T>();
+        RadioGroup {
+            label: Arc::new(RwLock::new(label)),
+            list,
+            horizontal: Arc::new(RwLock::new(false)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+            value_type: PhantomData,
+        }
+    }
+}
+
+impl<T> RadioGroup<T> {
+    /// Places the buttons in one row after the label instead of below each other.
+    pub fn horizontal(self) -> Self {
+        *self.horizontal.blocking_write() = true;
+        self
+    }
+
+    pub fn vertical(self) -> Self {
+        *self.horizontal.blocking_write() = false;
+        self
+    }
+}
+
+/// The selected variant, panics if `T::variants` is empty.
+impl<T: GuiEnum> Get<T> for RadioGroup<T> {
+    fn get(&self) -> T {
+        enum_variant(self.list.blocking_read().selected)
+            .expect("a GuiEnum needs at least one variant")
+    }
+}
+
+impl<T: GuiEnum> Set<T> for RadioGroup<T> {
+    fn set(&self, value: T) {
+        self.list.blocking_write().selected = enum_index(value);
+    }
+}
+
+/// The index of the active button.
+impl<T> Get<usize> for RadioGroup<T> {
+    fn get(&self) -> usize {
+        self.list.blocking_read().selected
+    }
+}
+
+/// The label of the active button, None if there are no buttons.
+impl<T> Get<Option<String>> for RadioGroup<T> {
+    fn get(&self) -> Option<String> {
+        self.list.blocking_read().selected_item()
+    }
+}
+
+/// Activates the button with the index, does nothing if the index is out of range.
+impl<T> Set<usize> for RadioGroup<T> {
+    fn set(&self, value: usize) {
+        self.list.blocking_write().select_index(value);
+    }
+}
+
+/// Activates the button with the label, does nothing if there is no such button.
+impl<T> Set<&str> for RadioGroup<T> {
+    fn set(&self, value: &str) {
+        self.list.blocking_write().select(value);
+    }
+}
+
+impl<T: 'static> Update for RadioGroup<T> {
+    fn update(&self, _gui: &Gui) -> bool {
+        let label = self.label.blocking_read();
+        let visible_label = label
+            .trim_end_matches('\0')
+            .split("##")
+            .next()
+            .unwrap_or_default();
+        let horizontal = *self.horizontal.blocking_read();
+        let mut same_line = false;
+        if !visible_label.is_empty() {
+            unsafe { ImGui_Text(visible_label.as_ptr(), visible_label.len()) }
+            same_line = horizontal;
+        }
+
+        let mut list = self.list.blocking_write();
+        let mut clicked = None;
+        unsafe { ImGui_PushStrID(label.as_ptr()) }
+        for (i, item) in list.items.iter().enumerate() {
+            let active = i == list.selected;
+            unsafe {
+                if same_line {
+                    ImGui_SameLine(0.0, -1.0);
+                }
+                same_line = horizontal;
+                ImGui_PushID(i as i32);
+                if ImGui_RadioButton(item.as_ptr(), item.len(), active) && !active {
+                    clicked = Some(i);
+                }
+                ImGui_PopID();
+            }
+        }
+        unsafe { ImGui_PopID() }
+
+        if let Some(i) = clicked {
+            list.selected = i;
+        }
+        clicked.is_some()
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: F) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A text which can be selected by clicking it, clicking again deselects it.
+#[derive(Clone)]
+pub struct Selectable {
+    label: Arc<RwLock<String>>,
+    value: Arc<RwLock<bool>>,
+    flags: Arc<RwLock<i32>>,
+    size: Arc<RwLock<ImGui_Vec2>>,
+    double_clicked: Arc<RwLock<bool>>,
+    callback: Callback,
+}
+
+impl Selectable {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+        Selectable {
+            label: Arc::new(RwLock::new(label)),
+            value: Arc::new(RwLock::new(false)),
+            flags: Arc::new(RwLock::new(0)),
+            size: Arc::new(RwLock::new(ImGui_Vec2::default())),
+            double_clicked: Arc::new(RwLock::new(false)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    /// Extends the highlighted area over all columns of a table.
+    pub fn span_all_columns(self) -> Self {
+        *self.flags.blocking_write() |= SELECTABLE_SPAN_ALL_COLUMNS;
+        self
+    }
+
+    /// Reports double clicks with `double_clicked`. The callback is called for the first click,
+    /// which toggles the value, and again for the double click, which does not.
+    pub fn allow_double_click(self) -> Self {
+        *self.flags.blocking_write() |= SELECTABLE_ALLOW_DOUBLE_CLICK;
+        self
+    }
+
+    /// A size of zero uses the available width and the text height.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        *self.size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
+
+    /// True if the last click was a double click.
+    pub fn double_clicked(&self) -> bool {
+        *self.double_clicked.blocking_read()
+    }
+}
+
+impl Set<bool> for Selectable {
+    fn set(&self, value: bool) {
+        *self.value.blocking_write() = value;
+    }
+}
+
+impl Get<bool> for Selectable {
+    fn get(&self) -> bool {
+        *self.value.blocking_read()
+    }
+}
+
+impl Update for Selectable {
+    fn update(&self, _gui: &Gui) -> bool {
+        let label = self.label.blocking_read();
+        let mut value = self.value.blocking_write();
+        let events = unsafe {
+            ImGui_Selectable(
+                label.as_ptr(),
+                label.len() - 1,
+                *value,
+                *self.flags.blocking_read(),
+                *self.size.blocking_read(),
+            )
+        };
+        if events & SELECTABLE_CLICKED == 0 {
+            return false;
+        }
+        //the first click of a double click already toggled the value
+        let double_clicked = events & SELECTABLE_DOUBLE_CLICKED != 0;
+        if !double_clicked {
+            *value = !*value;
+        }
+        *self.double_clicked.blocking_write() = double_clicked;
+        true
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: F) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(items: &[&str]) -> ItemList {
+        let mut list = ItemList::new();
+        list.multi_select = true;
+        list.set_items(items.iter().map(|item| String::from(*item)).collect());
+        list
+    }
+
+    #[test]
+    fn single_select_click() {
+        let mut list = list(&["a", "b", "c"]);
+        list.multi_select = false;
+        assert!(list.click(2, &[0, 1, 2], 0));
+        assert!(!list.click(2, &[0, 1, 2], KEY_MOD_CTRL));
+        assert_eq!(list.selection(), [2]);
+    }
+
+    #[test]
+    fn click_selects_only_the_item() {
+        let mut list = list(&["a", "b", "c"]);
+        list.click(0, &[0, 1, 2], 0);
+        list.click(2, &[0, 1, 2], 0);
+        assert_eq!(list.selection(), [2]);
+        assert_eq!(list.selected, 2);
+    }
+
+    #[test]
+    fn ctrl_toggles() {
+        let mut list = list(&["a", "b", "c"]);
+        list.click(0, &[0, 1, 2], 0);
+        list.click(2, &[0, 1, 2], KEY_MOD_CTRL);
+        assert_eq!(list.selection(), [0, 2]);
+        list.click(0, &[0, 1, 2], KEY_MOD_CTRL);
+        assert_eq!(list.selection(), [2]);
+        assert_eq!(list.selected, 0);
+    }
+
+    #[test]
+    fn shift_selects_range_from_anchor() {
+        let mut list = list(&["a", "b", "c", "d", "e"]);
+        let visible = [0, 1, 2, 3, 4];
+        list.click(3, &visible, 0);
+        list.click(1, &visible, KEY_MOD_SHIFT);
+        assert_eq!(list.selection(), [1, 2, 3]);
+        //the anchor stays, so the range can be changed
+        list.click(4, &visible, KEY_MOD_SHIFT);
+        assert_eq!(list.selection(), [3, 4]);
+        //ctrl+shift adds the range to the selection
+        list.click(0, &visible, KEY_MOD_CTRL);
+        list.click(1, &visible, KEY_MOD_CTRL | KEY_MOD_SHIFT);
+        assert_eq!(list.selection(), [0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn shift_range_skips_filtered_items() {
+        let mut list = list(&["a", "b", "c", "d"]);
+        list.click(0, &[0, 2, 3], 0);
+        list.click(3, &[0, 2, 3], KEY_MOD_SHIFT);
+        assert_eq!(list.selection(), [0, 2, 3]);
+    }
+
+    #[test]
+    fn shift_range_after_removing_items() {
+        let mut list = list(&["a", "b", "c", "d", "e"]);
+        list.click(1, &[0, 1, 2, 3, 4], 0);
+        list.click(3, &[0, 1, 2, 3, 4], KEY_MOD_CTRL);
+        list.set_items(["b", "c", "e"].map(String::from).to_vec());
+        //the anchor "d" was removed, so the first item is the anchor
+        assert_eq!(list.selection(), [0]);
+        list.click(2, &[0, 1, 2], KEY_MOD_SHIFT);
+        assert_eq!(list.selection(), [0, 1, 2]);
+    }
+}
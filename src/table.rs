@@ -0,0 +1,358 @@
+use crate::backend::*;
+use crate::{Callback, Get, Gui, Update};
+use std::{any::Any, cmp::Ordering, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+/// Supplies the rows of a `Table`. Only the cells of the visible rows are requested each frame,
+/// so the data can be large without being copied into widgets.
+pub trait TableData: Send + Sync {
+    fn row_count(&self) -> usize;
+
+    /// The text of a cell.
+    fn cell(&self, row: usize, column: usize) -> String;
+
+    /// Called when the user changes the sorting, the first spec has the highest priority.
+    /// The default does not sort.
+    fn sort(&self, _specs: &[SortSpec]) {}
+}
+
+/// A struct shown as one row of a table, usually implemented with `#[derive(TableRow)]`.
+pub trait TableRow: Send + Sync + 'static {
+    fn columns() -> Vec<Column>;
+
+    /// The text of the cell in the column.
+    fn cell(&self, column: usize) -> String;
+
+    /// Orders two rows by the column.
+    fn compare(&self, other: &Self, column: usize) -> Ordering;
+}
+
+/// A column to sort by, has to match the struct in gui_lib.cpp.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SortSpec {
+    pub column: usize,
+    pub ascending: bool,
+}
+
+/// Sorts by the first spec and breaks ties with the following ones.
+fn compare_by<T>(
+    a: &T,
+    b: &T,
+    specs: &[SortSpec],
+    compare: impl Fn(&T, &T, usize) -> Ordering,
+) -> Ordering {
+    for spec in specs {
+        let ordering = compare(a, b, spec.column);
+        let ordering = if spec.ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Rows of structs, sorted in place when the user changes the sorting.
+impl<T: TableRow> TableData for Arc<RwLock<Vec<T>>> {
+    fn row_count(&self) -> usize {
+        self.blocking_read().len()
+    }
+
+    fn cell(&self, row: usize, column: usize) -> String {
+        let rows = self.blocking_read();
+        rows.get(row)
+            .map(|row| row.cell(column))
+            .unwrap_or_default()
+    }
+
+    fn sort(&self, specs: &[SortSpec]) {
+        self.blocking_write()
+            .sort_by(|a, b| compare_by(a, b, specs, T::compare));
+    }
+}
+
+/// Rows of strings, sorted by comparing the strings of a column.
+impl TableData for Arc<RwLock<Vec<Vec<String>>>> {
+    fn row_count(&self) -> usize {
+        self.blocking_read().len()
+    }
+
+    fn cell(&self, row: usize, column: usize) -> String {
+        let rows = self.blocking_read();
+        rows.get(row)
+            .and_then(|row| row.get(column))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn sort(&self, specs: &[SortSpec]) {
+        self.blocking_write().sort_by(|a, b| {
+            compare_by(a, b, specs, |a, b, column| {
+                a.get(column).cmp(&b.get(column))
+            })
+        });
+    }
+}
+
+/// The header and layout of a table column.
+#[derive(Clone)]
+pub struct Column {
+    header: String,
+    width: f32,
+    flags: i32,
+}
+
+impl Column {
+    pub fn new(header: &str) -> Self {
+        let mut header = String::from_str(header).unwrap();
+        if !header.ends_with('\0') {
+            header.push('\0');
+        };
+        Column {
+            header,
+            width: 0.0,
+            flags: 0,
+        }
+    }
+
+    /// A fixed width in pixels instead of sharing the available width with the other columns.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self.flags |= COLUMN_WIDTH_FIXED;
+        self
+    }
+
+    /// Shares the available width with the other stretched columns, weighted by `weight`.
+    pub fn stretch(mut self, weight: f32) -> Self {
+        self.width = weight;
+        self.flags = (self.flags & !COLUMN_WIDTH_FIXED) | COLUMN_WIDTH_STRETCH;
+        self
+    }
+
+    pub fn no_resize(self) -> Self {
+        self.flag(COLUMN_NO_RESIZE)
+    }
+
+    pub fn no_reorder(self) -> Self {
+        self.flag(COLUMN_NO_REORDER)
+    }
+
+    pub fn no_hide(self) -> Self {
+        self.flag(COLUMN_NO_HIDE)
+    }
+
+    /// Hidden until the user shows it with the context menu of the header.
+    pub fn hidden(self) -> Self {
+        self.flag(COLUMN_DEFAULT_HIDE)
+    }
+
+    pub fn no_sort(self) -> Self {
+        self.flag(COLUMN_NO_SORT)
+    }
+
+    /// Sorts by this column when the table is shown the first time.
+    pub fn default_sort(self) -> Self {
+        self.flag(COLUMN_DEFAULT_SORT)
+    }
+
+    pub fn header(&self) -> &str {
+        self.header.trim_end_matches('\0')
+    }
+
+    fn flag(mut self, flag: i32) -> Self {
+        self.flags |= flag;
+        self
+    }
+}
+
+/// A scrollable table with a header row. The columns can be resized, reordered, hidden and sorted by
+/// the user. Rows outside of the visible area are skipped, so millions of rows stay fast.
+///
+/// The rows are supplied by a `TableData`, which also does the sorting.
+#[derive(Clone)]
+pub struct Table {
+    id: Arc<RwLock<String>>,
+    columns: Arc<RwLock<Vec<Column>>>,
+    data: Arc<RwLock<Arc<dyn TableData>>>,
+    flags: Arc<RwLock<i32>>,
+    size: Arc<RwLock<ImGui_Vec2>>,
+    sort_specs: Arc<RwLock<Vec<SortSpec>>>,
+    //sorts the data by sort_specs in the next frame, set when the data changed
+    resort: Arc<RwLock<bool>>,
+    callback: Callback,
+}
+
+impl Table {
+    pub fn new<T: TableData + 'static>(id: &str, data: T) -> Self {
+        let mut id = String::from_str(id).unwrap();
+        if !id.ends_with('\0') {
+            id.push('\0');
+        };
+        Table {
+            id: Arc::new(RwLock::new(id)),
+            columns: Arc::new(RwLock::new(vec![])),
+            data: Arc::new(RwLock::new(Arc::new(data))),
+            flags: Arc::new(RwLock::new(
+                TABLE_RESIZABLE
+                    | TABLE_REORDERABLE
+                    | TABLE_HIDEABLE
+                    | TABLE_SORTABLE
+                    | TABLE_ROW_BG
+                    | TABLE_BORDERS
+                    | TABLE_SCROLL_Y,
+            )),
+            size: Arc::new(RwLock::new(ImGui_Vec2::default())),
+            sort_specs: Arc::new(RwLock::new(vec![])),
+            resort: Arc::new(RwLock::new(false)),
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    /// A table with a column for every field of `T`. The rows are shared, so changes to the vector
+    /// from any thread are shown in the next frame. Call `resort` after changing them to keep the
+    /// rows sorted.
+    pub fn from_rows<T: TableRow>(id: &str, rows: Arc<RwLock<Vec<T>>>) -> Self {
+        let table = Table::new(id, rows);
+        table.columns.blocking_write().extend(T::columns());
+        table
+    }
+
+    pub fn column(self, column: Column) -> Self {
+        self.columns.blocking_write().push(column);
+        self
+    }
+
+    /// Replaces the data and sorts it by the current sort specs, can be called from any thread.
+    pub fn set_data<T: TableData + 'static>(&self, data: T) {
+        *self.data.blocking_write() = Arc::new(data);
+        self.resort();
+    }
+
+    /// Sorts the data again by the current sort specs in the next frame, e.g. after rows were
+    /// added. Does not call the callback.
+    pub fn resort(&self) {
+        *self.resort.blocking_write() = true;
+    }
+
+    /// Allows sorting by several columns with shift+click on the headers.
+    pub fn sort_multi(self) -> Self {
+        self.flag(TABLE_SORT_MULTI, true)
+    }
+
+    pub fn no_sort(self) -> Self {
+        self.flag(TABLE_SORTABLE, false)
+    }
+
+    pub fn no_borders(self) -> Self {
+        self.flag(TABLE_BORDERS, false)
+    }
+
+    /// Disables the alternating background color of the rows.
+    pub fn no_row_background(self) -> Self {
+        self.flag(TABLE_ROW_BG, false)
+    }
+
+    /// A size of zero fills the available space.
+    pub fn size(self, width: f32, height: f32) -> Self {
+        *self.size.blocking_write() = ImGui_Vec2 {
+            x: width,
+            y: height,
+        };
+        self
+    }
+
+    fn flag(self, flag: i32, enabled: bool) -> Self {
+        if enabled {
+            *self.flags.blocking_write() |= flag;
+        } else {
+            *self.flags.blocking_write() &= !flag;
+        }
+        self
+    }
+}
+
+/// The columns the table is currently sorted by.
+impl Get<Vec<SortSpec>> for Table {
+    fn get(&self) -> Vec<SortSpec> {
+        self.sort_specs.blocking_read().clone()
+    }
+}
+
+impl Update for Table {
+    fn update(&self, _gui: &Gui) -> bool {
+        let columns = self.columns.blocking_read();
+        if columns.is_empty()
+            || !unsafe {
+                ImGui_BeginTable(
+                    self.id.blocking_read().as_ptr(),
+                    columns.len() as i32,
+                    *self.flags.blocking_read(),
+                    *self.size.blocking_read(),
+                )
+            }
+        {
+            return false;
+        }
+
+        unsafe { ImGui_TableSetupScrollFreeze(0, 1) }
+        for column in columns.iter() {
+            unsafe { ImGui_TableSetupColumn(column.header.as_ptr(), column.flags, column.width) }
+        }
+        unsafe { ImGui_TableHeadersRow() }
+
+        //the data is cloned out of the lock so set_data does not wait for the frame
+        let data = self.data.blocking_read().clone();
+
+        //a table is sorted by at most all of its columns
+        let mut specs = vec![SortSpec::default(); columns.len()];
+        let count = unsafe { ImGui_TableSortSpecs(specs.as_mut_ptr(), specs.len()) };
+        let sorted = count >= 0;
+        let resort = std::mem::take(&mut *self.resort.blocking_write());
+        if sorted {
+            specs.truncate(count as usize);
+            data.sort(&specs);
+            *self.sort_specs.blocking_write() = specs;
+        } else if resort {
+            let specs = self.sort_specs.blocking_read().clone();
+            if !specs.is_empty() {
+                data.sort(&specs);
+            }
+        }
+
+        unsafe {
+            let clipper = ImGui_ListClipperBegin(data.row_count());
+            let (mut start, mut end) = (0, 0);
+            while ImGui_ListClipperStep(clipper, &mut start, &mut end) {
+                for row in start..end {
+                    ImGui_TableNextRow();
+                    for column in 0..columns.len() {
+                        if ImGui_TableSetColumnIndex(column as i32) {
+                            let text = data.cell(row, column);
+                            ImGui_Text(text.as_ptr(), text.len());
+                        }
+                    }
+                }
+            }
+            ImGui_ListClipperEnd(clipper);
+            ImGui_EndTable();
+        }
+        sorted
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        (self.callback.blocking_read())(gui);
+    }
+
+    fn set_callback<T: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: T) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
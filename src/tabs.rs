@@ -0,0 +1,310 @@
+use crate::backend::*;
+use crate::{Callback, Container2, Get, Gui, Update};
+use std::{any::Any, str::FromStr, sync::Arc};
+use tokio::sync::RwLock;
+
+/// A row of tabs of which one shows its content. Tabs added with `add` are created with the bar,
+/// `open_tab` adds tabs at runtime, e.g. one per opened document.
+#[derive(Clone)]
+pub struct TabBar {
+    id: Arc<RwLock<String>>,
+    flags: Arc<RwLock<i32>>,
+    items: Vec<Arc<dyn Update>>,
+    runtime_tabs: Arc<RwLock<Vec<TabItem>>>,
+    selected: Arc<RwLock<Option<String>>>,
+}
+
+impl TabBar {
+    pub fn new(id: &str) -> Self {
+        let mut id = String::from_str(id).unwrap();
+        if !id.ends_with('\0') {
+            id.push('\0');
+        };
+
+        TabBar {
+            id: Arc::new(RwLock::new(id)),
+            flags: Arc::new(RwLock::new(0)),
+            items: vec![],
+            runtime_tabs: Arc::new(RwLock::new(vec![])),
+            selected: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Allows dragging the tabs to reorder them.
+    pub fn reorderable(self) -> Self {
+        *self.flags.blocking_write() |= TAB_BAR_REORDERABLE;
+        self
+    }
+
+    /// Selects tabs added with `open_tab`.
+    pub fn auto_select_new_tabs(self) -> Self {
+        *self.flags.blocking_write() |= TAB_BAR_AUTO_SELECT_NEW_TABS;
+        self
+    }
+
+    /// Adds a tab at runtime, can be called from any thread and from callbacks.
+    pub fn open_tab(&self, tab: TabItem) {
+        self.runtime_tabs.blocking_write().push(tab);
+    }
+
+    /// Closes the tab with the label without calling its close callback, see `tab` for how the
+    /// label is matched. Tabs added at runtime are removed, the others can be shown again with
+    /// `TabItem::reopen`.
+    pub fn close_tab(&self, label: &str) {
+        if let Some(tab) = self.tab(label) {
+            *tab.open.blocking_write() = false;
+        }
+        self.runtime_tabs
+            .blocking_write()
+            .retain(|tab| *tab.open.blocking_read());
+    }
+
+    /// Selects the tab with the label in the next frame, see `tab` for how the label is matched.
+    pub fn select(&self, label: &str) {
+        if let Some(tab) = self.tab(label) {
+            tab.select();
+        }
+    }
+
+    /// The tab with the label, including closed tabs which were added with `add`.
+    ///
+    /// The label is compared with the full label of the tabs first, e.g. `"untitled##3"`, which
+    /// `TabItem::label` returns. Without such a tab the first tab showing the label is returned,
+    /// e.g. the first one of several `"untitled##N"` tabs for `"untitled"`.
+    pub fn tab(&self, label: &str) -> Option<TabItem> {
+        let tabs = self.tabs();
+        let exact = tabs.iter().find(|tab| tab.label() == label);
+        exact
+            .or_else(|| {
+                tabs.iter()
+                    .find(|tab| tab.label().split("##").next() == Some(label))
+            })
+            .cloned()
+    }
+
+    fn tabs(&self) -> Vec<TabItem> {
+        let runtime_tabs = self.runtime_tabs.blocking_read().clone();
+        self.items
+            .iter()
+            .filter_map(|item| item.as_any().downcast_ref::<TabItem>())
+            .cloned()
+            .chain(runtime_tabs)
+            .collect()
+    }
+}
+
+/// The full label of the selected tab, including a `##id` suffix.
+impl Get<Option<String>> for TabBar {
+    fn get(&self) -> Option<String> {
+        self.selected.blocking_read().clone()
+    }
+}
+
+impl Container2 for TabBar {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for TabBar {
+    fn update(&self, gui: &Gui) -> bool {
+        if !unsafe {
+            ImGui_BeginTabBar(
+                self.id.blocking_read().as_ptr(),
+                *self.flags.blocking_read(),
+            )
+        } {
+            return false;
+        }
+
+        //cloned so callbacks can open and close tabs
+        let runtime_tabs = self.runtime_tabs.blocking_read().clone();
+        let widgets = self
+            .items
+            .iter()
+            .map(|item| item.as_ref())
+            .chain(runtime_tabs.iter().map(|tab| tab as &dyn Update));
+        for widget in widgets {
+            if widget.update(gui) {
+                widget.call_callback(gui);
+            }
+        }
+        unsafe { ImGui_EndTabBar() }
+
+        let selected = self
+            .tabs()
+            .into_iter()
+            .find(|tab| *tab.selected.blocking_read())
+            .map(|tab| tab.label());
+        *self.selected.blocking_write() = selected;
+        self.runtime_tabs
+            .blocking_write()
+            .retain(|tab| *tab.open.blocking_read());
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A tab of a `TabBar` showing its widgets while it is selected.
+///
+/// The callback is called when the tab gets selected, the close callback when the user closes
+/// a `closable` tab.
+#[derive(Clone)]
+pub struct TabItem {
+    label: Arc<RwLock<String>>,
+    flags: Arc<RwLock<i32>>,
+    closable: Arc<RwLock<bool>>,
+    open: Arc<RwLock<bool>>,
+    selected: Arc<RwLock<bool>>,
+    //events of the last frame, dispatched in call_callback
+    got_selected: Arc<RwLock<bool>>,
+    got_closed: Arc<RwLock<bool>>,
+    items: Vec<Arc<dyn Update>>,
+    callback: Callback,
+    close_callback: Callback,
+}
+
+impl TabItem {
+    pub fn new(label: &str) -> Self {
+        let mut label = String::from_str(label).unwrap();
+        if !label.ends_with('\0') {
+            label.push('\0');
+        };
+
+        TabItem {
+            label: Arc::new(RwLock::new(label)),
+            flags: Arc::new(RwLock::new(0)),
+            closable: Arc::new(RwLock::new(false)),
+            open: Arc::new(RwLock::new(true)),
+            selected: Arc::new(RwLock::new(false)),
+            got_selected: Arc::new(RwLock::new(false)),
+            got_closed: Arc::new(RwLock::new(false)),
+            items: vec![],
+            callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+            close_callback: Arc::new(RwLock::new(Box::new(|_gui: &Gui| {}))),
+        }
+    }
+
+    /// Shows a close button on the tab.
+    pub fn closable(self) -> Self {
+        *self.closable.blocking_write() = true;
+        self
+    }
+
+    pub fn set_close_callback<T: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: T) -> Self {
+        self.close_callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    /// Shows a dot in the tab, e.g. for documents with unsaved changes.
+    pub fn set_unsaved(&self, unsaved: bool) {
+        if unsaved {
+            *self.flags.blocking_write() |= TAB_ITEM_UNSAVED;
+        } else {
+            *self.flags.blocking_write() &= !TAB_ITEM_UNSAVED;
+        }
+    }
+
+    /// Selects the tab in the next frame.
+    pub fn select(&self) {
+        *self.flags.blocking_write() |= TAB_ITEM_SET_SELECTED;
+    }
+
+    /// The label passed to `new`, including a `##id` suffix which is not shown.
+    pub fn label(&self) -> String {
+        String::from(self.label.blocking_read().trim_end_matches('\0'))
+    }
+
+    /// Shows a closed tab again, only for tabs not added with `TabBar::open_tab`.
+    pub fn reopen(&self) {
+        *self.open.blocking_write() = true;
+    }
+}
+
+/// True if the tab is selected.
+impl Get<bool> for TabItem {
+    fn get(&self) -> bool {
+        *self.selected.blocking_read()
+    }
+}
+
+impl Container2 for TabItem {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for TabItem {
+    fn update(&self, gui: &Gui) -> bool {
+        if !*self.open.blocking_read() {
+            *self.selected.blocking_write() = false;
+            return false;
+        }
+
+        //the selection request only applies to one frame
+        let flags = {
+            let mut flags = self.flags.blocking_write();
+            let current = *flags;
+            *flags &= !TAB_ITEM_SET_SELECTED;
+            current
+        };
+        let closable = *self.closable.blocking_read();
+        let mut open = true;
+        let selected = unsafe {
+            ImGui_BeginTabItem(
+                self.label.blocking_read().as_ptr(),
+                if closable {
+                    &mut open
+                } else {
+                    std::ptr::null_mut()
+                },
+                flags,
+            )
+        };
+        if selected {
+            for widget in &self.items {
+                if widget.update(gui) {
+                    widget.call_callback(gui);
+                }
+            }
+            unsafe { ImGui_EndTabItem() }
+        }
+
+        let was_selected = std::mem::replace(&mut *self.selected.blocking_write(), selected);
+        *self.got_selected.blocking_write() = selected && !was_selected;
+        *self.got_closed.blocking_write() = !open;
+        if !open {
+            *self.open.blocking_write() = false;
+        }
+        (selected && !was_selected) || !open
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        if std::mem::take(&mut *self.got_selected.blocking_write()) {
+            (self.callback.blocking_read())(gui);
+        }
+        if std::mem::take(&mut *self.got_closed.blocking_write()) {
+            (self.close_callback.blocking_read())(gui);
+        }
+    }
+
+    fn set_callback<T: 'static + Send + Sync + Fn(&Gui)>(mut self, callback: T) -> Self {
+        self.callback = Arc::new(RwLock::new(Box::new(callback)));
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
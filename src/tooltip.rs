@@ -0,0 +1,178 @@
+use crate::backend::*;
+use crate::{Container2, Get, Gui, Set, Update};
+use std::{any::Any, sync::Arc};
+use tokio::sync::RwLock;
+
+/// Shows its text and widgets while the previous widget is hovered.
+#[derive(Clone)]
+pub struct Tooltip {
+    text: Arc<RwLock<String>>,
+    items: Vec<Arc<dyn Update>>,
+}
+
+impl Tooltip {
+    /// The text is shown above the widgets and may be empty.
+    pub fn new(text: &str) -> Self {
+        Tooltip {
+            text: Arc::new(RwLock::new(String::from(text))),
+            items: vec![],
+        }
+    }
+}
+
+impl Set<String> for Tooltip {
+    fn set(&self, value: String) {
+        *self.text.blocking_write() = value;
+    }
+}
+
+impl Set<&str> for Tooltip {
+    fn set(&self, value: &str) {
+        *self.text.blocking_write() = String::from(value);
+    }
+}
+
+impl Get<String> for Tooltip {
+    fn get(&self) -> String {
+        self.text.blocking_read().clone()
+    }
+}
+
+impl Container2 for Tooltip {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.items
+    }
+}
+
+impl Update for Tooltip {
+    fn update(&self, gui: &Gui) -> bool {
+        if !unsafe { ImGui_BeginItemTooltip() } {
+            return false;
+        }
+        let text = self.text.blocking_read();
+        if !text.is_empty() {
+            unsafe { ImGui_Text(text.as_ptr(), text.len()) }
+        }
+        for widget in &self.items {
+            if widget.update(gui) {
+                widget.call_callback(gui);
+            }
+        }
+        unsafe { ImGui_EndTooltip() }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A widget with a tooltip, created with `.tooltip(text)` or `.with_tooltip(tooltip)` on any widget.
+///
+/// Behaves like the wrapped widget, `gui.get` and `gui.set` find it by the type of the wrapped
+/// widget. Widgets added with `add` are shown in the tooltip.
+#[derive(Clone)]
+pub struct WithTooltip<W: Update> {
+    widget: W,
+    tooltip: Tooltip,
+}
+
+impl<W: Update> WithTooltip<W> {
+    pub fn new(widget: W, tooltip: Tooltip) -> Self {
+        WithTooltip { widget, tooltip }
+    }
+}
+
+impl<W: Update + Get<T>, T> Get<T> for WithTooltip<W> {
+    fn get(&self) -> T {
+        self.widget.get()
+    }
+}
+
+impl<W: Update + Set<T>, T> Set<T> for WithTooltip<W> {
+    fn set(&self, value: T) {
+        self.widget.set(value)
+    }
+}
+
+impl<W: Update> Container2 for WithTooltip<W> {
+    fn get_items(&self) -> &Vec<Arc<dyn Update>> {
+        &self.tooltip.items
+    }
+
+    fn get_mut_items(&mut self) -> &mut Vec<Arc<dyn Update>> {
+        &mut self.tooltip.items
+    }
+}
+
+impl<W: Update> Update for WithTooltip<W> {
+    fn update(&self, gui: &Gui) -> bool {
+        let changed = self.widget.update(gui);
+        self.tooltip.update(gui);
+        changed
+    }
+
+    fn call_callback(&self, gui: &Gui) {
+        self.widget.call_callback(gui);
+    }
+
+    fn set_callback<F: 'static + Send + Sync + Fn(&Gui)>(self, callback: F) -> Self {
+        WithTooltip {
+            widget: self.widget.set_callback(callback),
+            tooltip: self.tooltip,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.widget.as_any()
+    }
+}
+
+/// A grayed out "(?)" showing the help text while it is hovered, usually placed with `same_line`
+/// behind the widget it explains.
+#[derive(Clone)]
+pub struct HelpMarker {
+    tooltip: Tooltip,
+}
+
+impl HelpMarker {
+    pub fn new(text: &str) -> Self {
+        HelpMarker {
+            tooltip: Tooltip::new(text),
+        }
+    }
+}
+
+impl Set<String> for HelpMarker {
+    fn set(&self, value: String) {
+        self.tooltip.set(value);
+    }
+}
+
+impl Set<&str> for HelpMarker {
+    fn set(&self, value: &str) {
+        self.tooltip.set(value);
+    }
+}
+
+impl Get<String> for HelpMarker {
+    fn get(&self) -> String {
+        self.tooltip.get()
+    }
+}
+
+impl Update for HelpMarker {
+    fn update(&self, gui: &Gui) -> bool {
+        let marker = "(?)";
+        unsafe { ImGui_TextDisabled(marker.as_ptr(), marker.len()) }
+        self.tooltip.update(gui)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,56 @@
+use rust_gui::*;
+
+#[derive(Clone, Copy, PartialEq, Debug, GuiEnum)]
+enum Quote {
+    Plain,
+    #[gui(label = "say \"hi\"\n\ttab \u{e9}")]
+    Escaped,
+    #[gui(label = r"C:\path")]
+    Raw,
+    #[gui(label = r#"raw "quoted""#)]
+    RawHashes,
+}
+
+#[test]
+fn gui_enum_labels() {
+    assert_eq!(
+        Quote::variants(),
+        [Quote::Plain, Quote::Escaped, Quote::Raw, Quote::RawHashes]
+    );
+    assert_eq!(Quote::Plain.label(), "Plain");
+    assert_eq!(Quote::Escaped.label(), "say \"hi\"\n\ttab é");
+    assert_eq!(Quote::Raw.label(), "C:\\path");
+    assert_eq!(Quote::RawHashes.label(), "raw \"quoted\"");
+}
+
+#[derive(TableRow)]
+struct File {
+    #[table(header = "name\n\"quoted\"")]
+    name: String,
+    #[table(header = r"size\MB", format = "{:.1}", sort_key = r"bytes")]
+    megabytes: f32,
+    #[table(skip)]
+    bytes: u64,
+}
+
+#[test]
+fn table_row_headers() {
+    let headers: Vec<String> = File::columns()
+        .iter()
+        .map(|column| String::from(column.header()))
+        .collect();
+    assert_eq!(headers, ["name\n\"quoted\"", "size\\MB"]);
+
+    let small = File {
+        name: String::from("a"),
+        megabytes: 2.0,
+        bytes: 1,
+    };
+    let large = File {
+        name: String::from("b"),
+        megabytes: 1.0,
+        bytes: 2,
+    };
+    assert_eq!(small.cell(1), "2.0");
+    assert_eq!(small.compare(&large, 1), std::cmp::Ordering::Less);
+}